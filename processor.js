@@ -5,6 +5,10 @@ class RustNoiseProcessor extends AudioWorkletProcessor {
         super();
         this.engine = null;
         this.ready = false;
+        this.meterBlocks = 0;
+        // 'sidechain-buffer' で送られたキー音声 (ライブ入力が無い時にブロック単位で消費)
+        this.sidechainQueue = [];
+        this.sidechainOffset = 0;
 
         // メインスレッドからのメッセージ処理
         this.port.onmessage = async (event) => {
@@ -21,7 +25,7 @@ class RustNoiseProcessor extends AudioWorkletProcessor {
                     console.log('[Processor] ✓ BUILD 020.2 - Wave Synthesis + Filter Character ACTIVE (with polyfill.js)');
                     
                     // メインスレッドに準備完了を通知
-                    this.port.postMessage({ type: 'engine-ready' });
+                    this.port.postMessage({ type: 'engine-ready', filterParamVersion: this.engine.filter_param_version() });
                 } catch (e) {
                     console.error('[Processor] ✗ Wasm init failed', e);
                     this.port.postMessage({ type: 'engine-error', error: e.message });
@@ -66,17 +70,89 @@ class RustNoiseProcessor extends AudioWorkletProcessor {
                     this.engine.set_fold_amount(event.data.value);
                     this.port.postMessage({ type: 'ack', key: 'fold_amount', value: event.data.value, timestamp: Date.now() });
                 }
+            } else if (type === 'fold-type') {
+                if (this.engine) {
+                    this.engine.set_fold_type(event.data.value);
+                    console.log(`[Processor] Fold Type: ${event.data.value}`);
+                }
+            } else if (type === 'fold-bias') {
+                if (this.engine) {
+                    this.engine.set_fold_bias(event.data.value);
+                    console.log(`[Processor] Fold Bias: ${event.data.value}`);
+                }
             } else if (type === 'bit-depth') {
                 if (this.engine) {
                     this.engine.set_bit_depth(event.data.value);
                     this.port.postMessage({ type: 'ack', key: 'bit_depth', value: event.data.value, timestamp: Date.now() });
                 }
+            } else if (type === 'decimator') {
+                if (this.engine) {
+                    const { rateHz, jitter } = event.data;
+                    this.engine.set_decimator(rateHz, jitter);
+                    console.log(`[Processor] Decimator: ${rateHz} ${jitter}`);
+                }
+            } else if (type === 'crush-curve') {
+                if (this.engine) {
+                    this.engine.set_crush_curve(event.data.value);
+                    console.log(`[Processor] Crush Curve: ${event.data.value}`);
+                }
+            } else if (type === 'crush-dither') {
+                if (this.engine) {
+                    this.engine.set_crush_dither(event.data.value);
+                    console.log(`[Processor] Crush Dither: ${event.data.value}`);
+                }
             } else if (type === 'noise-gate-follow') {
                 if (this.engine) {
                     this.engine.set_noise_gate_follow(event.data.value);
                     this.port.postMessage({ type: 'ack', key: 'noise_gate_follow', value: event.data.value, timestamp: Date.now() });
                 }
             }
+            else if (type === 'noise-drone') {
+                if (this.engine) {
+                    this.engine.set_noise_drone(event.data.value);
+                    this.port.postMessage({ type: 'ack', key: 'noise_drone', value: event.data.value, timestamp: Date.now() });
+                }
+            } else if (type === 'noise-filter') {
+                if (this.engine) {
+                    const { cutoff, resonance } = event.data;
+                    this.engine.set_noise_filter(cutoff, resonance);
+                    this.port.postMessage({ type: 'ack', key: 'noise_filter', value: { cutoff, resonance }, timestamp: Date.now() });
+                }
+            } else if (type === 'noise-envelope') {
+                if (this.engine) {
+                    const { attack, release } = event.data;
+                    this.engine.set_noise_envelope(attack, release);
+                    this.port.postMessage({ type: 'ack', key: 'noise_envelope', value: { attack, release }, timestamp: Date.now() });
+                }
+            }
+            // BUILD 024: Noise colours / noise oscillator
+            else if (type === 'noise-type') {
+                if (this.engine) {
+                    this.engine.set_noise_type(event.data.value);
+                    this.port.postMessage({ type: 'ack', key: 'noise_type', value: event.data.value, timestamp: Date.now() });
+                }
+            } else if (type === 'noise-density') {
+                if (this.engine) {
+                    this.engine.set_noise_density(event.data.value);
+                    this.port.postMessage({ type: 'ack', key: 'noise_density', value: event.data.value, timestamp: Date.now() });
+                }
+            } else if (type === 'noise-digital') {
+                if (this.engine) {
+                    const { rateHz, shortMode } = event.data;
+                    this.engine.set_noise_digital(rateHz, shortMode);
+                    this.port.postMessage({ type: 'ack', key: 'noise_digital', value: { rateHz, shortMode }, timestamp: Date.now() });
+                }
+            } else if (type === 'noise-osc-level') {
+                if (this.engine) {
+                    this.engine.set_noise_osc_level(event.data.value);
+                    this.port.postMessage({ type: 'ack', key: 'noise_osc_level', value: event.data.value, timestamp: Date.now() });
+                }
+            } else if (type === 'noise-osc-type') {
+                if (this.engine) {
+                    this.engine.set_noise_osc_type(event.data.value);
+                    this.port.postMessage({ type: 'ack', key: 'noise_osc_type', value: event.data.value, timestamp: Date.now() });
+                }
+            }
             // Phase 1 追加: ADSR
             else if (type === 'set-adsr') {
                 if (this.engine) {
@@ -92,6 +168,29 @@ class RustNoiseProcessor extends AudioWorkletProcessor {
                     this.engine.set_synth_type(event.data.synthType || event.data.value);
                     console.log(`[Processor] Synth Type: ${event.data.synthType || event.data.value}`);
                 }
+            } else if (type === 'set-osc2-type') {
+                if (this.engine) {
+                    this.engine.set_osc2_type(event.data.value);
+                    console.log(`[Processor] Osc2 Type: ${event.data.value}`);
+                }
+            } else if (type === 'set-osc-tuning') {
+                if (this.engine) {
+                    const { slot, coarse, fine } = event.data;
+                    this.engine.set_osc_tuning(slot, coarse, fine);
+                    console.log(`[Processor] Osc${slot + 1} Tuning: ${coarse}st ${fine}ct`);
+                }
+            } else if (type === 'set-osc-level') {
+                if (this.engine) {
+                    const { slot, value } = event.data;
+                    this.engine.set_osc_level(slot, value);
+                    console.log(`[Processor] Osc${slot + 1} Level: ${value}`);
+                }
+            } else if (type === 'set-osc-cross-mod') {
+                if (this.engine) {
+                    const { fm, ring, sync } = event.data;
+                    this.engine.set_osc_cross_mod(fm, ring, sync);
+                    console.log(`[Processor] Osc Cross Mod: FM ${fm} Ring ${ring} Sync ${sync}`);
+                }
             } else if (type === 'set-fm-ratio') {
                 if (this.engine) {
                     this.engine.set_fm_ratio(event.data.value);
@@ -162,19 +261,83 @@ class RustNoiseProcessor extends AudioWorkletProcessor {
             // ========== FILTER CHARACTER (BUILD 019) ==========
             else if (type === 'filter-q') {
                 if (this.engine) {
+                    // 非推奨: v1のQ値としてマイグレーション経由でレゾナンスに変換
+                    console.warn('[Processor] filter-q is deprecated; use resonance or load-filter-params');
                     this.engine.set_filter_q(event.data.value);
-                    console.log(`[Processor] Filter Q: ${event.data.value}`);
                 }
             } else if (type === 'filter-damping') {
                 if (this.engine) {
                     this.engine.set_filter_damping(event.data.value);
                     console.log(`[Processor] Filter Damping: ${event.data.value}`);
                 }
+            } else if (type === 'load-filter-params') {
+                if (this.engine) {
+                    // 保存時のバージョンから現行モデルへ変換し、結果をメインスレッドに返す
+                    const { version, resonance, filterQ, damping } = event.data;
+                    this.engine.load_filter_params(version, resonance || 0, filterQ || 0, damping || 0);
+                    this.port.postMessage({
+                        type: 'filter-params',
+                        version: this.engine.filter_param_version(),
+                        resonance: this.engine.resonance(),
+                        damping: this.engine.filter_damping()
+                    });
+                    console.log(`[Processor] Filter Params: v${version} → ${this.engine.resonance()} ${this.engine.filter_damping()}`);
+                }
             } else if (type === 'filter-drive') {
                 if (this.engine) {
                     this.engine.set_filter_drive(event.data.value);
                     console.log(`[Processor] Filter Drive: ${event.data.value}`);
                 }
+            } else if (type === 'filter-type') {
+                if (this.engine) {
+                    this.engine.set_filter_type(event.data.value);
+                    console.log(`[Processor] Filter Type: ${event.data.value}`);
+                }
+            } else if (type === 'filter-key-track') {
+                if (this.engine) {
+                    const { amount, centerNote } = event.data;
+                    this.engine.set_filter_key_track(amount, centerNote);
+                    console.log(`[Processor] Filter Key Track: ${amount} ${centerNote}`);
+                }
+            } else if (type === 'filter-env') {
+                if (this.engine) {
+                    const { attack, decay, sustain, release } = event.data;
+                    this.engine.set_filter_env(attack, decay, sustain, release);
+                    console.log(`[Processor] Filter Env: ${attack} ${decay} ${sustain} ${release}`);
+                }
+            } else if (type === 'filter-env-amount') {
+                if (this.engine) {
+                    const { amount, velocitySens } = event.data;
+                    this.engine.set_filter_env_amount(amount, velocitySens);
+                    console.log(`[Processor] Filter Env Amount: ${amount} ${velocitySens}`);
+                }
+            } else if (type === 'formant') {
+                if (this.engine) {
+                    const { mix, vowel, voiceSet } = event.data;
+                    this.engine.set_formant(mix, vowel, voiceSet);
+                    console.log(`[Processor] Formant: ${mix} ${vowel} ${voiceSet}`);
+                }
+            } else if (type === 'formant-mod') {
+                if (this.engine) {
+                    const { lfoVowel, shVowel } = event.data;
+                    this.engine.set_formant_mod(lfoVowel, shVowel);
+                    console.log(`[Processor] Formant Mod: ${lfoVowel} ${shVowel}`);
+                }
+            } else if (type === 'filter-mode') {
+                if (this.engine) {
+                    this.engine.set_filter_mode(event.data.value);
+                    console.log(`[Processor] Filter Mode: ${event.data.value}`);
+                }
+            } else if (type === 'filter-morph') {
+                if (this.engine) {
+                    this.engine.set_filter_morph(event.data.value);
+                    console.log(`[Processor] Filter Morph: ${event.data.value}`);
+                }
+            } else if (type === 'filter-slope') {
+                if (this.engine) {
+                    this.engine.set_filter_slope(event.data.value);
+                    console.log(`[Processor] Filter Slope: ${event.data.value}`);
+                }
             }
             
             // ========== CHAOS ENGINE (BUILD 017) ==========
@@ -215,6 +378,90 @@ class RustNoiseProcessor extends AudioWorkletProcessor {
                     this.engine.set_diffusion_mix(event.data.value);
                     console.log(`[Processor] Diffusion Mix: ${event.data.value}`);
                 }
+            } else if (type === 'set-diffusion') {
+                if (this.engine) {
+                    const { size, modulation } = event.data;
+                    this.engine.set_diffusion(size, modulation);
+                    console.log(`[Processor] Diffusion: ${size} ${modulation}`);
+                }
+            } else if (type === 'set-delay') {
+                if (this.engine) {
+                    const { mix, time, feedback, mode } = event.data;
+                    this.engine.set_delay(mix, time, feedback, mode);
+                    console.log(`[Processor] Delay: ${mix} ${time} ${feedback} ${mode}`);
+                }
+            } else if (type === 'delay-sync') {
+                if (this.engine) {
+                    const { enabled, bpm, division } = event.data;
+                    this.engine.set_delay_sync(enabled, bpm, division);
+                    console.log(`[Processor] Delay sync: ${enabled} ${bpm} ${division}`);
+                }
+            } else if (type === 'delay-tone') {
+                if (this.engine) {
+                    const { lowCut, highCut } = event.data;
+                    this.engine.set_delay_feedback_tone(lowCut, highCut);
+                    console.log(`[Processor] Delay tone: ${lowCut} ${highCut}`);
+                }
+            } else if (type === 'delay-grime') {
+                if (this.engine) {
+                    const { drive, bitDepth, crushRate } = event.data;
+                    this.engine.set_delay_grime(drive, bitDepth, crushRate);
+                    console.log(`[Processor] Delay grime: ${drive} ${bitDepth} ${crushRate}`);
+                }
+            } else if (type === 'delay-duck') {
+                if (this.engine) {
+                    this.engine.set_delay_duck(event.data.value);
+                    console.log(`[Processor] Delay duck: ${event.data.value}`);
+                }
+            } else if (type === 'delay-modulation') {
+                if (this.engine) {
+                    const { depth, rate } = event.data;
+                    this.engine.set_delay_modulation(depth, rate);
+                    console.log(`[Processor] Delay modulation: ${depth} ${rate}`);
+                }
+            } else if (type === 'set-reverb') {
+                if (this.engine) {
+                    const { mix, size, decay, predelay } = event.data;
+                    this.engine.set_reverb(mix, size, decay, predelay);
+                    console.log(`[Processor] Reverb: ${mix} ${size} ${decay} ${predelay}`);
+                }
+            } else if (type === 'reverb-tone') {
+                if (this.engine) {
+                    const { damping, modulation } = event.data;
+                    this.engine.set_reverb_tone(damping, modulation);
+                    console.log(`[Processor] Reverb tone: ${damping} ${modulation}`);
+                }
+            } else if (type === 'reverb-freeze') {
+                if (this.engine) {
+                    this.engine.set_reverb_freeze(event.data.value);
+                    console.log(`[Processor] Reverb freeze: ${event.data.value}`);
+                }
+            } else if (type === 'reverb-reset') {
+                if (this.engine) {
+                    this.engine.reset_reverb();
+                    console.log('[Processor] Reverb reset');
+                }
+            } else if (type === 'load-ir') {
+                if (this.engine) {
+                    // スペクトルはメインスレッドで prepare_impulse_response 済み (ここではFFTしない)
+                    const { spectra } = event.data;
+                    if (this.engine.load_impulse_response(spectra)) {
+                        console.log(`[Processor] IR loaded: ${spectra.length} spectrum values`);
+                    } else {
+                        console.warn(`[Processor] IR rejected: ${spectra.length} spectrum values exceed the 4 s capacity`);
+                    }
+                }
+            } else if (type === 'clear-ir') {
+                if (this.engine) {
+                    this.engine.clear_impulse_response();
+                    console.log('[Processor] IR cleared');
+                }
+            } else if (type === 'set-convolution') {
+                if (this.engine) {
+                    const { mix, predelay } = event.data;
+                    this.engine.set_convolution(mix, predelay);
+                    console.log(`[Processor] Convolution: ${mix} ${predelay}`);
+                }
             } 
             // Phase 1 追加: sub, saturation, tilt, post-gain, limiter
             else if (type === 'set-sub') {
@@ -227,10 +474,60 @@ class RustNoiseProcessor extends AudioWorkletProcessor {
                     const { drive, mix } = event.data;
                     this.engine.set_saturation(drive, mix);
                 }
+            } else if (type === 'set-saturation-model') {
+                if (this.engine) {
+                    this.engine.set_saturation_model(event.data.value);
+                }
+            } else if (type === 'set-saturation-character') {
+                if (this.engine) {
+                    const { bias, tone } = event.data;
+                    this.engine.set_saturation_character(bias, tone);
+                }
             } else if (type === 'set-tilt') {
                 if (this.engine) {
                     this.engine.set_tilt(event.data.value);
                 }
+            } else if (type === 'eq-band') {
+                if (this.engine) {
+                    const { index, bandType, freq, gain, q, slope } = event.data;
+                    this.engine.set_eq_band(index, bandType, freq, gain, q, slope);
+                    console.log(`[Processor] EQ band: ${index} ${bandType} ${freq} ${gain} ${q} ${slope}`);
+                }
+            } else if (type === 'eq-band-enabled') {
+                if (this.engine) {
+                    const { index, enabled } = event.data;
+                    this.engine.set_eq_band_enabled(index, enabled);
+                    console.log(`[Processor] EQ band enabled: ${index} ${enabled}`);
+                }
+            } else if (type === 'set-multiband') {
+                if (this.engine) {
+                    const { mix, lowHz, highHz } = event.data;
+                    this.engine.set_multiband(mix, lowHz, highHz);
+                    console.log(`[Processor] Multiband: ${mix} ${lowHz} ${highHz}`);
+                }
+            } else if (type === 'multiband-band') {
+                if (this.engine) {
+                    const { index, model, drive, bitDepth, crushRate, gain } = event.data;
+                    this.engine.set_multiband_band(index, model, drive, bitDepth, crushRate, gain);
+                    console.log(`[Processor] Multiband band: ${index} ${model} ${drive} ${bitDepth} ${crushRate} ${gain}`);
+                }
+            } else if (type === 'stereo-width') {
+                if (this.engine) {
+                    this.engine.set_stereo_width(event.data.value);
+                    console.log(`[Processor] Stereo width: ${event.data.value}`);
+                }
+            } else if (type === 'bass-mono') {
+                if (this.engine) {
+                    const { enabled, freq } = event.data;
+                    this.engine.set_bass_mono(enabled, freq);
+                    console.log(`[Processor] Bass mono: ${enabled} ${freq}`);
+                }
+            } else if (type === 'set-haas') {
+                if (this.engine) {
+                    const { delay, amount } = event.data;
+                    this.engine.set_haas(delay, amount);
+                    console.log(`[Processor] Haas: ${delay} ${amount}`);
+                }
             } else if (type === 'set-post-gain') {
                 if (this.engine) {
                     this.engine.set_post_gain(event.data.value);
@@ -239,6 +536,45 @@ class RustNoiseProcessor extends AudioWorkletProcessor {
                 if (this.engine) {
                     this.engine.set_limiter(event.data.value);
                 }
+            } else if (type === 'limiter-mode') {
+                if (this.engine) {
+                    this.engine.set_limiter_mode(event.data.value);
+                    console.log(`[Processor] Limiter mode: ${event.data.value}`);
+                }
+            } else if (type === 'limiter-params') {
+                if (this.engine) {
+                    const { lookahead, release, ceiling, truePeak } = event.data;
+                    this.engine.set_limiter_params(lookahead, release, ceiling, truePeak);
+                    console.log(`[Processor] Limiter: ${lookahead} ${release} ${ceiling} ${truePeak}`);
+                }
+            } else if (type === 'set-compressor') {
+                if (this.engine) {
+                    const { threshold, ratio, knee, makeup, mix } = event.data;
+                    this.engine.set_compressor(threshold, ratio, knee, makeup, mix);
+                    console.log(`[Processor] Compressor: ${threshold} ${ratio} ${knee} ${makeup} ${mix}`);
+                }
+            } else if (type === 'compressor-timing') {
+                if (this.engine) {
+                    const { attack, release, detector } = event.data;
+                    this.engine.set_compressor_timing(attack, release, detector);
+                    console.log(`[Processor] Compressor timing: ${attack} ${release} ${detector}`);
+                }
+            } else if (type === 'sidechain-buffer') {
+                // 外部キー音声をキューに積む (Float32Array, コンテキストのサンプルレート)
+                const { buffer, replace } = event.data;
+                if (replace) {
+                    this.sidechainQueue = [];
+                    this.sidechainOffset = 0;
+                }
+                if (buffer && buffer.length > 0) {
+                    this.sidechainQueue.push(buffer);
+                }
+            } else if (type === 'compressor-sidechain') {
+                if (this.engine) {
+                    const { source, noteId } = event.data;
+                    this.engine.set_compressor_sidechain(source, noteId);
+                    console.log(`[Processor] Compressor sidechain: ${source} ${noteId}`);
+                }
             }
             // Phase 1 追加: LFO, Sample & Hold, Jitter, Chorus
             else if (type === 'set-lfo') {
@@ -261,6 +597,17 @@ class RustNoiseProcessor extends AudioWorkletProcessor {
                     const { mix, rate, depth, feedback } = event.data;
                     this.engine.set_chorus(mix, rate, depth, feedback);
                 }
+            } else if (type === 'chorus-mode') {
+                if (this.engine) {
+                    const { mode, taps, throughZero } = event.data;
+                    this.engine.set_chorus_mode(mode, taps, throughZero);
+                    console.log(`[Processor] Chorus mode: ${mode} ${taps} ${throughZero}`);
+                }
+            } else if (type === 'chorus-global') {
+                if (this.engine) {
+                    this.engine.set_chorus_global(event.data.value);
+                    console.log(`[Processor] Chorus global: ${event.data.value}`);
+                }
             } else if (type === 'set-spasm') {
                 if (this.engine) {
                     this.engine.set_spasm(event.data.value);
@@ -350,13 +697,52 @@ class RustNoiseProcessor extends AudioWorkletProcessor {
                         timestamp: Date.now() 
                     });
                 }
+            } else if (type === 'comb-mode') {
+                if (this.engine) {
+                    const { mode, negative } = event.data;
+                    this.engine.set_comb_mode(mode, negative);
+                    console.log(`[Processor] Comb mode: ${mode} ${negative}`);
+                }
+            } else if (type === 'comb-key-track') {
+                if (this.engine) {
+                    const { enabled, semitones } = event.data;
+                    this.engine.set_comb_key_track(enabled, semitones);
+                    console.log(`[Processor] Comb key track: ${enabled} ${semitones}`);
+                }
+            }
+
+            // ========== BUILD 024: VOICE STAGE ROUTING ==========
+            else if (type === 'fx-chain') {
+                if (this.engine) {
+                    try {
+                        const warning = this.engine.set_fx_chain(event.data.value);
+                        if (warning) {
+                            // 旧ルートの tilt はマスターEQへ移動済み
+                            console.warn(`[Processor] ⚠️ ${warning}`);
+                            this.port.postMessage({ type: 'warning', message: warning, timestamp: Date.now() });
+                        }
+                        this.port.postMessage({ type: 'ack', key: 'fx_chain', value: event.data.value, timestamp: Date.now() });
+                    } catch (e) {
+                        console.warn(`[Processor] ⚠️ Invalid FX chain: ${e}`);
+                        this.port.postMessage({ type: 'warning', message: `Invalid FX chain: ${e}`, timestamp: Date.now() });
+                    }
+                }
+            } else if (type === 'fx-chain-reset') {
+                if (this.engine) {
+                    this.engine.reset_fx_chain();
+                    this.port.postMessage({ type: 'ack', key: 'fx_chain', value: 'default', timestamp: Date.now() });
+                }
             }
 
             // ========== NOTE CONTROL ==========
             else if (type === 'note-on') {
                 if (this.engine) {
-                    const { noteId, frequency, modIndex } = event.data;
-                    this.engine.note_on(noteId, frequency, modIndex);
+                    const { noteId, frequency, modIndex, velocity } = event.data;
+                    if (velocity !== undefined) {
+                        this.engine.note_on_velocity(noteId, frequency, modIndex, velocity);
+                    } else {
+                        this.engine.note_on(noteId, frequency, modIndex);
+                    }
                 }
             } else if (type === 'note-off') {
                 if (this.engine) {
@@ -384,16 +770,54 @@ class RustNoiseProcessor extends AudioWorkletProcessor {
         const output = outputs[0];
         const channel0 = output[0]; // モノラル出力
 
-        // Rustにバッファを渡し、DSP処理を実行
-        this.engine.process(channel0);
+        // 外部サイドチェイン: ノードの入力0をコンプレッサーのキーとして渡す
+        // 入力が未接続なら 'sidechain-buffer' のキューから1ブロック分を渡す
+        const sidechain = inputs[0];
+        if (sidechain && sidechain.length > 0) {
+            this.engine.set_sidechain_input(sidechain[0]);
+        } else if (this.sidechainQueue.length > 0) {
+            this.engine.set_sidechain_input(this.nextSidechainBlock(channel0.length));
+        }
 
-        // ステレオの場合、右チャンネルにコピー
+        // Rustにバッファを渡し、DSP処理を実行
+        // ステレオの場合はマスター(ディフュージョン以降)をステレオで処理
         if (output.length > 1) {
-            output[1].set(channel0);
+            this.engine.process_stereo(channel0, output[1]);
+        } else {
+            this.engine.process(channel0);
+        }
+
+        // リミッター/コンプレッサーのゲインリダクションを約30fpsで通知
+        if (++this.meterBlocks >= 12) {
+            this.meterBlocks = 0;
+            this.port.postMessage({ type: 'limiter-gr', value: this.engine.limiter_gain_reduction() });
+            this.port.postMessage({ type: 'compressor-gr', value: this.engine.compressor_gain_reduction() });
         }
 
         return true;
     }
+
+    // キューから `length` サンプルを切り出す (ブロック境界をまたぐバッファにも対応)
+    nextSidechainBlock(length) {
+        if (!this.sidechainBlock || this.sidechainBlock.length !== length) {
+            this.sidechainBlock = new Float32Array(length);
+        }
+        const block = this.sidechainBlock;
+        block.fill(0);
+        let filled = 0;
+        while (filled < length && this.sidechainQueue.length > 0) {
+            const head = this.sidechainQueue[0];
+            const count = Math.min(length - filled, head.length - this.sidechainOffset);
+            block.set(head.subarray(this.sidechainOffset, this.sidechainOffset + count), filled);
+            filled += count;
+            this.sidechainOffset += count;
+            if (this.sidechainOffset >= head.length) {
+                this.sidechainQueue.shift();
+                this.sidechainOffset = 0;
+            }
+        }
+        return block;
+    }
 }
 
 registerProcessor('rust-noise-processor', RustNoiseProcessor);
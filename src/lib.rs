@@ -1,4 +1,7 @@
+#![allow(clippy::manual_clamp, clippy::too_many_arguments, clippy::upper_case_acronyms, clippy::new_without_default)]
+
 use wasm_bindgen::prelude::*;
+use std::collections::VecDeque;
 use std::f32::consts::PI;
 
 /// LFO (Low Frequency Oscillator)
@@ -81,7 +84,7 @@ impl SampleHold {
             x ^= x >> 17;
             x ^= x << 5;
             self.seed = x;
-            self.target_value = (x as f32 / std::u32::MAX as f32) * 2.0 - 1.0;
+            self.target_value = (x as f32 / u32::MAX as f32) * 2.0 - 1.0;
         }
         
         self.current_value += (self.target_value - self.current_value) * self.slew_coeff;
@@ -115,24 +118,214 @@ impl Jitter {
         x ^= x << 5;
         self.seed = x;
         
-        let random = (x as f32 / std::u32::MAX as f32) * 2.0 - 1.0;
+        let random = (x as f32 / u32::MAX as f32) * 2.0 - 1.0;
         let bandwidth = (band_hz / self.sample_rate).min(0.01);
         
         random * bandwidth
     }
 }
 
+/// BUILD 024: Noise colours
+#[derive(Clone, Copy, PartialEq)]
+pub enum NoiseType {
+    White = 0,
+    Pink = 1,
+    Brown = 2,
+    Blue = 3,
+    Velvet = 4,
+    Crackle = 5,
+    Digital = 6,
+}
+
+impl NoiseType {
+    fn from_index(index: usize) -> Self {
+        match index {
+            0 => NoiseType::White,
+            1 => NoiseType::Pink,
+            2 => NoiseType::Brown,
+            3 => NoiseType::Blue,
+            4 => NoiseType::Velvet,
+            5 => NoiseType::Crackle,
+            6 => NoiseType::Digital,
+            _ => NoiseType::White,
+        }
+    }
+}
+
+/// BUILD 024: Noise Generator
+struct NoiseGenerator {
+    noise_type: NoiseType,
+    seed: u32,
+    sample_rate: f32,
+    
+    // Pink (Paul Kellet's refined filter bank)
+    pink: [f32; 7],
+    // Brown (leaky integrator)
+    brown: f32,
+    // Blue (differentiated pink)
+    last_pink: f32,
+    
+    // Velvet / Crackle impulse density (impulses per second)
+    density: f32,
+    velvet_counter: f32,
+    velvet_pos: f32,
+    velvet_sign: f32,
+    crackle_level: f32,
+    
+    // Digital (LFSR bit pattern)
+    lfsr: u16,
+    lfsr_phase: f32,
+    lfsr_rate_hz: f32,
+    lfsr_short: bool,
+}
+
+impl NoiseGenerator {
+    fn new(sample_rate: f32, seed: u32) -> Self {
+        Self {
+            noise_type: NoiseType::White,
+            seed: seed.max(1),
+            sample_rate,
+            pink: [0.0; 7],
+            brown: 0.0,
+            last_pink: 0.0,
+            density: 1000.0,
+            velvet_counter: 0.0,
+            velvet_pos: 0.0,
+            velvet_sign: 1.0,
+            crackle_level: 0.0,
+            lfsr: 0x7FFF,
+            lfsr_phase: 0.0,
+            lfsr_rate_hz: 8000.0,
+            lfsr_short: false,
+        }
+    }
+    
+    fn set_type(&mut self, noise_type: NoiseType) {
+        self.noise_type = noise_type;
+    }
+    
+    fn set_density(&mut self, hz: f32) {
+        self.density = hz.max(1.0).min(10000.0);
+    }
+    
+    fn set_digital(&mut self, rate_hz: f32, short_mode: bool) {
+        self.lfsr_rate_hz = rate_hz.max(20.0).min(self.sample_rate * 0.5);
+        self.lfsr_short = short_mode;
+    }
+    
+    fn white(&mut self) -> f32 {
+        let mut x = self.seed;
+        x ^= x << 13;
+        x ^= x >> 17;
+        x ^= x << 5;
+        self.seed = x;
+        (x as f32 / u32::MAX as f32) * 2.0 - 1.0
+    }
+    
+    fn pink(&mut self) -> f32 {
+        let white = self.white();
+        let b = &mut self.pink;
+        b[0] = 0.99886 * b[0] + white * 0.0555179;
+        b[1] = 0.99332 * b[1] + white * 0.0750759;
+        b[2] = 0.96900 * b[2] + white * 0.153852;
+        b[3] = 0.86650 * b[3] + white * 0.3104856;
+        b[4] = 0.55000 * b[4] + white * 0.5329522;
+        b[5] = -0.7616 * b[5] - white * 0.0168980;
+        let out = b[0] + b[1] + b[2] + b[3] + b[4] + b[5] + b[6] + white * 0.5362;
+        b[6] = white * 0.115926;
+        out * 0.11
+    }
+    
+    fn tick(&mut self) -> f32 {
+        match self.noise_type {
+            NoiseType::White => self.white(),
+            NoiseType::Pink => self.pink(),
+            NoiseType::Brown => {
+                let white = self.white();
+                self.brown = (self.brown + white * 0.02) / 1.02;
+                (self.brown * 3.5).max(-1.0).min(1.0)
+            }
+            NoiseType::Blue => {
+                let pink = self.pink();
+                let blue = pink - self.last_pink;
+                self.last_pink = pink;
+                (blue * 2.0).max(-1.0).min(1.0)
+            }
+            NoiseType::Velvet => {
+                // One signed impulse at a random position inside each period
+                let period = (self.sample_rate / self.density).max(1.0);
+                if self.velvet_counter >= period {
+                    self.velvet_counter -= period;
+                    self.velvet_pos = (self.white() * 0.5 + 0.5) * (period - 1.0);
+                    self.velvet_sign = self.white().signum();
+                }
+                let out = if self.velvet_counter.floor() == self.velvet_pos.floor() {
+                    self.velvet_sign
+                } else {
+                    0.0
+                };
+                self.velvet_counter += 1.0;
+                out
+            }
+            NoiseType::Crackle => {
+                // Sparse random clicks with a short decaying tail
+                let chance = self.density * 0.1 / self.sample_rate;
+                if self.white() * 0.5 + 0.5 < chance {
+                    let amp = self.white();
+                    self.crackle_level = amp * amp * amp.signum();
+                }
+                let out = self.crackle_level * self.white().abs();
+                self.crackle_level *= 0.95;
+                out
+            }
+            NoiseType::Digital => {
+                self.lfsr_phase += self.lfsr_rate_hz / self.sample_rate;
+                while self.lfsr_phase >= 1.0 {
+                    self.lfsr_phase -= 1.0;
+                    let tap = if self.lfsr_short { 6 } else { 1 };
+                    let bit = (self.lfsr ^ (self.lfsr >> tap)) & 1;
+                    self.lfsr = (self.lfsr >> 1) | (bit << 14);
+                }
+                if self.lfsr & 1 == 0 { 1.0 } else { -1.0 }
+            }
+        }
+    }
+}
+
+/// BUILD 024: Modulated delay character
+#[derive(Clone, Copy, PartialEq)]
+pub enum ChorusMode {
+    Chorus = 0,
+    Flanger = 1,
+    Vibrato = 2,
+}
+
+impl ChorusMode {
+    fn from_index(index: usize) -> Self {
+        match index {
+            1 => ChorusMode::Flanger,
+            2 => ChorusMode::Vibrato,
+            _ => ChorusMode::Chorus,
+        }
+    }
+}
+
 /// Chorus
+/// BUILD 024: Hermite-interpolated taps (1-4, phase-offset), flanger/vibrato modes and through-zero flanging
 struct Chorus {
     buffer: Vec<f32>,
     write_pos: usize,
     lfo_phase: f32,
     sample_rate: f32,
+    mode: ChorusMode,
+    taps: usize,
+    through_zero: bool,
 }
 
 impl Chorus {
     fn new(sample_rate: f32) -> Self {
-        let max_delay_ms = 50.0;
+        // Depth tops out at 50 ms and swings +50%
+        let max_delay_ms = 80.0;
         let buffer_size = ((max_delay_ms / 1000.0) * sample_rate) as usize;
         
         Self {
@@ -140,18 +333,56 @@ impl Chorus {
             write_pos: 0,
             lfo_phase: 0.0,
             sample_rate,
+            mode: ChorusMode::Chorus,
+            taps: 1,
+            through_zero: false,
         }
     }
     
-    fn process(&mut self, input: f32, rate_hz: f32, depth_ms: f32, feedback: f32) -> f32 {
-        let delay_samples = (depth_ms / 1000.0 * self.sample_rate) as usize;
-        let lfo = (self.lfo_phase * 2.0 * PI).sin();
-        let modulated_delay = (delay_samples as f32 * (1.0 + lfo * 0.5)).max(1.0) as usize;
+    fn set_mode(&mut self, mode: ChorusMode, taps: usize, through_zero: bool) {
+        self.mode = mode;
+        self.taps = taps.max(1).min(4);
+        self.through_zero = through_zero;
+    }
+    
+    /// Returns the dry/wet blend; through-zero replaces the dry path with a fixed reference tap.
+    /// Vibrato only becomes pure pitch modulation at full mix.
+    fn process(&mut self, input: f32, mix: f32, rate_hz: f32, depth_ms: f32, feedback: f32) -> f32 {
+        let ms = self.sample_rate / 1000.0;
+        let taps = if self.mode == ChorusMode::Chorus { self.taps } else { 1 };
+        
+        let mut wet = 0.0;
+        let mut first_tap = 0.0;
+        for tap in 0..taps {
+            let mut phase = self.lfo_phase + tap as f32 / taps as f32;
+            if phase >= 1.0 {
+                phase -= 1.0;
+            }
+            let lfo = (phase * 2.0 * PI).sin();
+            
+            let delay_ms = match self.mode {
+                ChorusMode::Chorus => depth_ms * (1.0 + lfo * 0.5),
+                // Flanger sweeps 0.1 ms up to a fifth of the depth setting
+                ChorusMode::Flanger if self.through_zero => depth_ms * 0.2 * (1.0 + lfo),
+                ChorusMode::Flanger => 0.1 + depth_ms * 0.2 * (0.5 + 0.5 * lfo),
+                ChorusMode::Vibrato => depth_ms * 0.25 * (1.0 + lfo * 0.9),
+            };
+            let delayed = read_fractional(&self.buffer, self.write_pos, (delay_ms * ms).max(2.0));
+            if tap == 0 {
+                first_tap = delayed;
+            }
+            wet += delayed;
+        }
+        wet /= taps as f32;
         
-        let read_pos = (self.write_pos + self.buffer.len() - modulated_delay) % self.buffer.len();
-        let delayed = self.buffer[read_pos];
+        let feedback = if self.mode == ChorusMode::Vibrato { 0.0 } else { feedback.max(0.0).min(0.99) };
+        let dry = if self.mode == ChorusMode::Flanger && self.through_zero {
+            read_fractional(&self.buffer, self.write_pos, (depth_ms * 0.2 * ms).max(2.0))
+        } else {
+            input
+        };
         
-        self.buffer[self.write_pos] = input + delayed * feedback.max(0.0).min(0.99);
+        self.buffer[self.write_pos] = flush_denormal(input + first_tap * feedback);
         self.write_pos = (self.write_pos + 1) % self.buffer.len();
         
         self.lfo_phase += rate_hz / self.sample_rate;
@@ -159,7 +390,34 @@ impl Chorus {
             self.lfo_phase -= 1.0;
         }
         
-        delayed
+        dry * (1.0 - mix) + wet * mix
+    }
+}
+
+/// BUILD 024: SVF response
+#[derive(Clone, Copy, PartialEq)]
+pub enum FilterMode {
+    Lowpass = 0,
+    Bandpass = 1,
+    Highpass = 2,
+    Notch = 3,
+    Peak = 4,
+    Allpass = 5,
+    Morph = 6,
+}
+
+impl FilterMode {
+    fn from_index(index: usize) -> Self {
+        match index {
+            0 => FilterMode::Lowpass,
+            1 => FilterMode::Bandpass,
+            2 => FilterMode::Highpass,
+            3 => FilterMode::Notch,
+            4 => FilterMode::Peak,
+            5 => FilterMode::Allpass,
+            6 => FilterMode::Morph,
+            _ => FilterMode::Lowpass,
+        }
     }
 }
 
@@ -176,6 +434,11 @@ impl SVFilter {
     }
     
     fn process(&mut self, input: f32, cutoff: f32, resonance: f32) -> f32 {
+        self.process_mode(input, cutoff, resonance, FilterMode::Lowpass, 0.0)
+    }
+    
+    /// BUILD 024: Multi-mode output; `morph` sweeps LP (0.0) → BP (0.5) → HP (1.0) in Morph mode
+    fn process_mode(&mut self, input: f32, cutoff: f32, resonance: f32, mode: FilterMode, morph: f32) -> f32 {
         let g = (PI * cutoff / self.sample_rate).tan();
         let k = 2.0 - 2.0 * resonance.min(0.99);
         
@@ -183,404 +446,809 @@ impl SVFilter {
         let v1 = (self.ic1eq + g * (v0 - self.ic2eq)) / (1.0 + g * (g + k));
         let v2 = self.ic2eq + g * v1;
         
-        self.ic1eq = 2.0 * v1 - self.ic1eq;
-        self.ic2eq = 2.0 * v2 - self.ic2eq;
+        self.ic1eq = flush_denormal(2.0 * v1 - self.ic1eq);
+        self.ic2eq = flush_denormal(2.0 * v2 - self.ic2eq);
         
-        v2
+        let low = v2;
+        let band = v1;
+        let high = v0 - k * v1 - v2;
+        
+        match mode {
+            FilterMode::Lowpass => low,
+            FilterMode::Bandpass => band,
+            FilterMode::Highpass => high,
+            FilterMode::Notch => low + high,
+            FilterMode::Peak => low - high,
+            FilterMode::Allpass => v0 - 2.0 * k * band,
+            FilterMode::Morph => {
+                let m = morph.max(0.0).min(1.0) * 2.0;
+                if m < 1.0 {
+                    low * (1.0 - m) + band * m
+                } else {
+                    band * (2.0 - m) + high * (m - 1.0)
+                }
+            }
+        }
     }
 }
 
-/// Tilt EQ
-struct TiltEQ {
-    low_shelf: f32,
-    high_shelf: f32,
+/// BUILD 024: Filter model selector
+#[derive(Clone, Copy, PartialEq)]
+pub enum FilterType {
+    Svf = 0,
+    Ladder = 1,
+    DiodeLadder = 2,
 }
 
-impl TiltEQ {
-    fn new() -> Self {
-        Self { low_shelf: 0.0, high_shelf: 0.0 }
+impl FilterType {
+    fn from_index(index: usize) -> Self {
+        match index {
+            0 => FilterType::Svf,
+            1 => FilterType::Ladder,
+            2 => FilterType::DiodeLadder,
+            _ => FilterType::Svf,
+        }
+    }
+}
+
+/// BUILD 024: ZDF Moog-style 4-pole ladder (tanh input stage keeps self-oscillation bounded)
+struct LadderFilter {
+    state: [f32; 4],
+    sample_rate: f32,
+}
+
+impl LadderFilter {
+    fn new(sample_rate: f32) -> Self {
+        Self { state: [0.0; 4], sample_rate }
     }
     
-    fn process(&mut self, input: f32, tilt: f32, sample_rate: f32) -> f32 {
-        let tilt_clamped = tilt.max(-1.0).min(1.0);
-        
-        let low_coeff = 1.0 - (-2.0 * PI * 200.0 / sample_rate).exp();
-        let high_coeff = 1.0 - (-2.0 * PI * 2000.0 / sample_rate).exp();
+    fn process(&mut self, input: f32, cutoff: f32, resonance: f32) -> f32 {
+        let g = (PI * cutoff.min(self.sample_rate * 0.45) / self.sample_rate).tan();
+        let big_g = g / (1.0 + g);
+        // k = 4 is the self-oscillation point (reached just below resonance 1.0)
+        let k = resonance.max(0.0) * 4.3;
         
-        self.low_shelf += (input - self.low_shelf) * low_coeff;
-        self.high_shelf += (input - self.high_shelf) * high_coeff;
+        // Passband gain compensation; the loop solve and the input stage both see `x`
+        let x = input * (1.0 + k * 0.5);
         
-        let bass_boost = self.low_shelf * tilt_clamped.max(0.0) * 0.5;
-        let treble_boost = (input - self.high_shelf) * (-tilt_clamped).max(0.0) * 0.5;
+        // Solve the zero-delay feedback loop for the linear estimate of the output
+        let s = self.state;
+        let sigma = (big_g * big_g * big_g * s[0] + big_g * big_g * s[1] + big_g * s[2] + s[3]) / (1.0 + g);
+        let g4 = big_g * big_g * big_g * big_g;
+        let y_est = (g4 * x + sigma) / (1.0 + k * g4);
         
-        input + bass_boost + treble_boost
+        // Nonlinear input stage
+        let mut u = (x - k * y_est).tanh();
+        for stage in self.state.iter_mut() {
+            let v = (u - *stage) * big_g;
+            let lp = v + *stage;
+            *stage = flush_denormal(lp + v);
+            u = lp;
+        }
+        u
     }
 }
 
-/// BUILD 023: Enhanced Comb Filter with tanh in feedback loop
-struct CombFilter {
-    buffer: Vec<f32>,
-    write_pos: usize,
-    damping_state: f32,
+/// BUILD 024: Non-zero odd-offset taps of the 47-tap half-band FIR used for 2x oversampling
+const HALF_BAND_TAPS: usize = 24;
+
+/// BUILD 024: Polyphase half-band FIR (Blackman-windowed sinc) for 2x up/down sampling
+/// Every even-offset tap but the 0.5 centre is zero, so each phase only runs the odd taps.
+struct HalfBand {
+    coeffs: [f32; HALF_BAND_TAPS],
+    odd: [f32; HALF_BAND_TAPS],
+    even: [f32; HALF_BAND_TAPS],
+}
+
+impl HalfBand {
+    fn new() -> Self {
+        let span = (2 * HALF_BAND_TAPS - 2) as f32;
+        let mut coeffs = [0.0; HALF_BAND_TAPS];
+        for (i, coeff) in coeffs.iter_mut().enumerate() {
+            let offset = (2 * i) as f32 - (HALF_BAND_TAPS - 1) as f32;
+            let x = PI * offset * 0.5;
+            let m = 2.0 * PI * (2 * i) as f32 / span;
+            let window = 0.42 - 0.5 * m.cos() + 0.08 * (2.0 * m).cos();
+            *coeff = x.sin() / x * window;
+        }
+        // Unity DC gain: the odd taps sum to 0.5 alongside the 0.5 centre tap
+        let sum: f32 = coeffs.iter().sum();
+        for coeff in coeffs.iter_mut() {
+            *coeff *= 0.5 / sum;
+        }
+        Self { coeffs, odd: [0.0; HALF_BAND_TAPS], even: [0.0; HALF_BAND_TAPS] }
+    }
+    
+    fn push(history: &mut [f32; HALF_BAND_TAPS], x: f32) {
+        history.copy_within(..HALF_BAND_TAPS - 1, 1);
+        history[0] = x;
+    }
+    
+    fn odd_taps(&self) -> f32 {
+        self.coeffs.iter().zip(&self.odd).map(|(c, x)| c * x).sum()
+    }
+    
+    /// One input sample in, two samples at twice the rate out
+    fn upsample(&mut self, x: f32) -> [f32; 2] {
+        Self::push(&mut self.odd, x);
+        [2.0 * self.odd_taps(), self.odd[HALF_BAND_TAPS / 2 - 1]]
+    }
+    
+    /// Two samples at twice the rate in, one band-limited sample out
+    fn downsample(&mut self, pair: [f32; 2]) -> f32 {
+        Self::push(&mut self.even, pair[0]);
+        Self::push(&mut self.odd, pair[1]);
+        self.odd_taps() + 0.5 * self.even[HALF_BAND_TAPS / 2 - 1]
+    }
+}
+
+/// BUILD 024: TB-style diode ladder (coupled stages, nonlinear feedback)
+/// Runs at 2x between half-band up/down filters. The stages are integrated explicitly
+/// (forward Euler), not with a zero-delay solve, so `g` is clamped to keep them stable.
+struct DiodeLadderFilter {
+    v: [f32; 4],
     sample_rate: f32,
+    up: HalfBand,
+    down: HalfBand,
 }
 
-impl CombFilter {
+impl DiodeLadderFilter {
     fn new(sample_rate: f32) -> Self {
-        let max_delay_ms = 20.0;
-        let buffer_size = ((max_delay_ms / 1000.0) * sample_rate) as usize + 1;
+        Self { v: [0.0; 4], sample_rate, up: HalfBand::new(), down: HalfBand::new() }
+    }
+    
+    fn diode(x: f32) -> f32 {
+        2.0 * (x * 0.5).tanh()
+    }
+    
+    /// One step at the oversampled rate; returns the last stage
+    fn step(&mut self, input: f32, g: f32, k: f32) -> f32 {
+        let u = (input - k * self.v[3]).tanh();
+        let d0 = Self::diode(u - self.v[0]);
+        let d1 = Self::diode(self.v[0] - self.v[1]);
+        let d2 = Self::diode(self.v[1] - self.v[2]);
+        let d3 = Self::diode(self.v[2] - self.v[3]);
         
-        Self {
-            buffer: vec![0.0; buffer_size],
-            write_pos: 0,
-            damping_state: 0.0,
-            sample_rate,
+        self.v[0] += g * (d0 - d1);
+        self.v[1] += g * 0.5 * (d1 - d2);
+        self.v[2] += g * 0.5 * (d2 - d3);
+        self.v[3] += g * 0.5 * d3;
+        for v in self.v.iter_mut() {
+            *v = flush_denormal(*v);
         }
+        self.v[3]
     }
     
-    fn process(&mut self, input: f32, freq: f32, feedback: f32, damp: f32) -> f32 {
-        // BUILD 023: Exponential frequency mapping (50Hz - 1000Hz)
-        let freq_exp = 50.0 * (20.0_f32).powf(freq / 1000.0);
-        let freq_clamped = freq_exp.max(50.0).min(1000.0);
-        let delay_samples = (self.sample_rate / freq_clamped) as usize;
-        let delay_samples = delay_samples.min(self.buffer.len() - 1).max(1);
-        
-        let read_pos = (self.write_pos + self.buffer.len() - delay_samples) % self.buffer.len();
-        let delayed = self.buffer[read_pos];
-        
-        // Damping filter
-        let damp_coeff = 1.0 - damp.max(0.0).min(1.0);
-        self.damping_state += (delayed - self.damping_state) * damp_coeff;
+    fn process(&mut self, input: f32, cutoff: f32, resonance: f32) -> f32 {
+        let oversample = 2.0;
+        let g = (PI * cutoff.min(self.sample_rate * 0.45) / (self.sample_rate * oversample)).tan().min(0.9);
+        // The diode ladder needs much more loop gain than the transistor ladder to ring
+        let k = resonance.max(0.0) * 24.0;
         
-        // BUILD 023: tanh in feedback loop to prevent divergence
-        let feedback_clamped = feedback.max(0.0).min(0.99);
-        let feedback_signal = (self.damping_state * feedback_clamped).tanh();
+        let [a, b] = self.up.upsample(input);
+        let pair = [self.step(a, g, k), self.step(b, g, k)];
+        let output = self.down.downsample(pair);
         
-        self.buffer[self.write_pos] = input + feedback_signal;
-        self.write_pos = (self.write_pos + 1) % self.buffer.len();
+        // Output gain compensates the coupled-ladder passband loss
+        output * (1.0 + k * 0.25)
+    }
+}
+
+/// BUILD 024: Vowel formant tables (F1, F2, F3 in Hz), vowels A/E/I/O/U
+/// Peterson & Barney averages for male, female and child speakers
+const FORMANT_TABLE: [[[f32; 3]; 5]; 3] = [
+    // Male
+    [[730.0, 1090.0, 2440.0], [530.0, 1840.0, 2480.0], [270.0, 2290.0, 3010.0], [570.0, 840.0, 2410.0], [300.0, 870.0, 2240.0]],
+    // Female
+    [[850.0, 1220.0, 2810.0], [610.0, 2330.0, 2990.0], [310.0, 2790.0, 3310.0], [590.0, 920.0, 2710.0], [370.0, 950.0, 2670.0]],
+    // Child
+    [[1030.0, 1370.0, 3170.0], [690.0, 2610.0, 3570.0], [370.0, 3200.0, 3730.0], [680.0, 1060.0, 3180.0], [430.0, 1170.0, 3260.0]],
+];
+const FORMANT_GAINS: [f32; 3] = [1.0, 0.5, 0.25];
+const FORMANT_BANDWIDTHS: [f32; 3] = [80.0, 100.0, 140.0];
+
+/// BUILD 024: Formant filter (parallel bandpass bank)
+struct FormantFilter {
+    bands: [SVFilter; 3],
+}
+
+impl FormantFilter {
+    fn new(sample_rate: f32) -> Self {
+        Self {
+            bands: [SVFilter::new(sample_rate), SVFilter::new(sample_rate), SVFilter::new(sample_rate)],
+        }
+    }
+    
+    /// `vowel` sweeps continuously A (0.0) → E → I → O → U (4.0)
+    fn process(&mut self, input: f32, vowel: f32, voice_set: usize) -> f32 {
+        let table = &FORMANT_TABLE[voice_set.min(2)];
+        let pos = vowel.max(0.0).min(4.0);
+        let index = (pos.floor() as usize).min(3);
+        let blend = pos - index as f32;
+        // Children have wider formant bandwidths
+        let bw_scale = if voice_set == 2 { 1.5 } else { 1.0 };
         
-        self.damping_state
+        let mut output = 0.0;
+        for (band, filter) in self.bands.iter_mut().enumerate() {
+            let freq = table[index][band] * (1.0 - blend) + table[index + 1][band] * blend;
+            // SVF damping k = bandwidth / centre; scaling the band output by k gives unity peak gain
+            let k = (FORMANT_BANDWIDTHS[band] * bw_scale / freq).max(0.02).min(2.0);
+            let resonance = 1.0 - k * 0.5;
+            let band_out = filter.process_mode(input, freq, resonance, FilterMode::Bandpass, 0.0);
+            output += band_out * k * FORMANT_GAINS[band];
+        }
+        output
+    }
+}
+
+/// BUILD 024: Flush-to-zero for recursive state. WebAssembly has no FTZ/DAZ mode, so
+/// feedback loops decaying on silence would otherwise sink into slow denormal arithmetic.
+#[inline]
+fn flush_denormal(x: f32) -> f32 {
+    if x.abs() < 1.0e-20 { 0.0 } else { x }
+}
+
+/// BUILD 024: DC blocker (one-pole high-pass at ~10 Hz)
+struct DcBlocker {
+    x1: f32,
+    y1: f32,
+    r: f32,
+}
+
+impl DcBlocker {
+    fn new(sample_rate: f32) -> Self {
+        Self { x1: 0.0, y1: 0.0, r: 1.0 - 2.0 * PI * 10.0 / sample_rate }
     }
     
-    fn reset(&mut self) {
-        self.buffer.fill(0.0);
-        self.damping_state = 0.0;
-        self.write_pos = 0;
+    fn process(&mut self, input: f32) -> f32 {
+        let output = flush_denormal(input - self.x1 + self.r * self.y1);
+        self.x1 = input;
+        self.y1 = output;
+        output
     }
 }
 
+/// BUILD 024: Wavefolder algorithms
 #[derive(Clone, Copy, PartialEq)]
-enum EnvelopeState {
-    Idle,
-    Attack,
-    Decay,
-    Sustain,
-    Release,
+pub enum FoldType {
+    Modulo = 0,
+    Sine = 1,
+    Triangle = 2,
+    Serge = 3,
+    Asymmetric = 4,
 }
 
-pub struct Voice {
-    active: bool,
-    note_id: i32,
-    frequency: f32,
-    phase: f32,
-    mod_phase: f32,
-    mod_index_base: f32,
-    sample_rate: f32,
+impl FoldType {
+    fn from_index(index: usize) -> Self {
+        match index {
+            0 => FoldType::Modulo,
+            1 => FoldType::Sine,
+            2 => FoldType::Triangle,
+            3 => FoldType::Serge,
+            4 => FoldType::Asymmetric,
+            _ => FoldType::Modulo,
+        }
+    }
+}
+
+/// Reflect into [-1, 1] (Buchla 259-style triangle fold)
+fn triangle_fold(x: f32) -> f32 {
+    let t = x * 0.25 + 0.25;
+    4.0 * (t - t.round()).abs() - 1.0
+}
+
+/// BUILD 024: Saturation models
+#[derive(Clone, Copy, PartialEq)]
+pub enum SatModel {
+    Asymmetric = 0,
+    Tube = 1,
+    Tape = 2,
+    Diode = 3,
+    HardClip = 4,
+    Foldback = 5,
+    HalfRectify = 6,
+    FullRectify = 7,
+    DigitalOverload = 8,
+}
+
+impl SatModel {
+    fn from_index(index: usize) -> Self {
+        match index {
+            0 => SatModel::Asymmetric,
+            1 => SatModel::Tube,
+            2 => SatModel::Tape,
+            3 => SatModel::Diode,
+            4 => SatModel::HardClip,
+            5 => SatModel::Foldback,
+            6 => SatModel::HalfRectify,
+            7 => SatModel::FullRectify,
+            8 => SatModel::DigitalOverload,
+            _ => SatModel::Asymmetric,
+        }
+    }
+}
+
+/// BUILD 024: Saturator (model + bias + tone); returns the wet signal only
+struct Saturator {
+    model: SatModel,
+    bias: f32,
+    tone: f32,
+    tone_state: f32,
+    tone_coeff: f32,
+    tape_in: f32,
+    tape_out: f32,
+    dc: DcBlocker,
+}
+
+impl Saturator {
+    fn new(sample_rate: f32) -> Self {
+        Self {
+            model: SatModel::Asymmetric,
+            bias: 0.0,
+            tone: 0.0,
+            tone_state: 0.0,
+            tone_coeff: 1.0 - (-2.0 * PI * 1500.0 / sample_rate).exp(),
+            tape_in: 0.0,
+            tape_out: 0.0,
+            dc: DcBlocker::new(sample_rate),
+        }
+    }
     
-    // Hard Sync
-    master_phase: f32,
-    slave_phase: f32,
-    sync_amount: f32,
+    fn set_model(&mut self, model: SatModel) {
+        self.model = model;
+    }
     
-    // Ring Modulation
-    ring_phase: f32,
-    ring_ratio: f32,
-    ring_mix: f32,
+    fn set_character(&mut self, bias: f32, tone: f32) {
+        self.bias = bias.max(-1.0).min(1.0);
+        self.tone = tone.max(-1.0).min(1.0);
+    }
     
-    // BUILD 023: Wavetable
-    wavetable_position: f32,
+    fn shape(&self, x: f32) -> f32 {
+        match self.model {
+            SatModel::Asymmetric => {
+                // BUILD 023: Asymmetric distortion
+                if x > 0.0 {
+                    x.tanh()
+                } else {
+                    // Harder clipping on negative side
+                    (x * 1.2).tanh() * 0.95
+                }
+            }
+            SatModel::Tube => {
+                // Squared term adds even harmonics
+                let t = x.tanh();
+                t + 0.3 * t * t
+            }
+            SatModel::Tape => x.tanh(),
+            SatModel::Diode => x.signum() * (1.0 - (-x.abs()).exp()),
+            SatModel::HardClip => x.max(-1.0).min(1.0),
+            SatModel::Foldback => {
+                if x.abs() > 1.0 {
+                    triangle_fold(x)
+                } else {
+                    x
+                }
+            }
+            SatModel::HalfRectify => x.max(0.0).tanh(),
+            SatModel::FullRectify => x.abs().tanh(),
+            SatModel::DigitalOverload => {
+                // 16-bit integer overflow wraps around instead of clipping
+                let wrapped = (x * 32767.0) as i32 as i16;
+                wrapped as f32 / 32768.0
+            }
+        }
+    }
     
-    feedback: f32,
-    last_output: f32,
+    fn process(&mut self, input: f32, drive: f32) -> f32 {
+        let mut x = input * drive;
+        
+        if self.model == SatModel::Tape {
+            // Direction-dependent offset gives a hysteresis-like loop
+            x += 0.25 * (self.tape_out - self.tape_in);
+            self.tape_in = input * drive;
+        }
+        
+        // Subtracting the resting bias point keeps the output centred
+        let mut y = self.shape(x + self.bias) - self.shape(self.bias);
+        
+        if self.model == SatModel::Tape {
+            self.tape_out = y;
+        }
+        // Asymmetric curves, rectifiers and bias all leave DC behind
+        y = self.dc.process(y);
+        
+        // Tone: negative darkens towards a 1.5 kHz low-pass, positive lifts the highs
+        if self.tone != 0.0 {
+            self.tone_state = flush_denormal(self.tone_state + (y - self.tone_state) * self.tone_coeff);
+            y = if self.tone < 0.0 {
+                y * (1.0 + self.tone) - self.tone_state * self.tone
+            } else {
+                y + (y - self.tone_state) * self.tone
+            };
+        }
+        
+        y
+    }
+}
+
+/// BUILD 024: Bit reducer quantisation curves
+#[derive(Clone, Copy, PartialEq)]
+pub enum CrushCurve {
+    Linear = 0,
+    MuLaw = 1,
+    Logarithmic = 2,
+}
+
+impl CrushCurve {
+    fn from_index(index: usize) -> Self {
+        match index {
+            0 => CrushCurve::Linear,
+            1 => CrushCurve::MuLaw,
+            2 => CrushCurve::Logarithmic,
+            _ => CrushCurve::Linear,
+        }
+    }
+}
+
+/// BUILD 024: Bit reducer dither
+#[derive(Clone, Copy, PartialEq)]
+pub enum CrushDither {
+    None = 0,
+    Rectangular = 1,
+    Triangular = 2,
+}
+
+impl CrushDither {
+    fn from_index(index: usize) -> Self {
+        match index {
+            0 => CrushDither::None,
+            1 => CrushDither::Rectangular,
+            2 => CrushDither::Triangular,
+            _ => CrushDither::None,
+        }
+    }
+}
+
+const MU_LAW: f32 = 255.0;
+const A_LAW: f32 = 87.6;
+
+/// BUILD 024: Bit reducer (companded quantiser with optional dither)
+struct BitCrusher {
+    curve: CrushCurve,
+    dither: CrushDither,
+    seed: u32,
+}
+
+impl BitCrusher {
+    fn new(seed: u32) -> Self {
+        Self { curve: CrushCurve::Linear, dither: CrushDither::None, seed }
+    }
     
-    envelope_state: EnvelopeState,
-    env_counter: f32,
-    current_level: f32,
-    attack_ms: f32,
-    decay_ms: f32,
-    sustain: f32,
-    release_ms: f32,
-    
-    mod_env_counter: f32,
-    mod_env_level: f32,
-    
-    lfo: LFO,
-    sample_hold: SampleHold,
-    jitter: Jitter,
-    
-    cutoff: f32,
-    resonance: f32,
-    fold_amount: f32,
-    bit_depth: f32,
+    fn random(&mut self) -> f32 {
+        let mut x = self.seed;
+        x ^= x << 13;
+        x ^= x >> 17;
+        x ^= x << 5;
+        self.seed = x;
+        x as f32 / u32::MAX as f32 - 0.5
+    }
     
-    filter: SVFilter,
-    tilt_eq: TiltEQ,
-    chorus: Chorus,
-    comb_filter: CombFilter,
+    fn compress(&self, x: f32) -> f32 {
+        let a = x.abs().min(1.0);
+        let y = match self.curve {
+            CrushCurve::Linear => return x,
+            CrushCurve::MuLaw => (1.0 + MU_LAW * a).ln() / (1.0 + MU_LAW).ln(),
+            CrushCurve::Logarithmic => {
+                if a < 1.0 / A_LAW {
+                    A_LAW * a / (1.0 + A_LAW.ln())
+                } else {
+                    (1.0 + (A_LAW * a).ln()) / (1.0 + A_LAW.ln())
+                }
+            }
+        };
+        y * x.signum()
+    }
     
-    comb_mix: f32,
-    comb_freq: f32,
-    comb_feedback: f32,
-    comb_damp: f32,
+    fn expand(&self, y: f32) -> f32 {
+        let a = y.abs().min(1.0);
+        let x = match self.curve {
+            CrushCurve::Linear => return y,
+            CrushCurve::MuLaw => ((1.0 + MU_LAW).powf(a) - 1.0) / MU_LAW,
+            CrushCurve::Logarithmic => {
+                let ln_a = 1.0 + A_LAW.ln();
+                if a < 1.0 / ln_a {
+                    a * ln_a / A_LAW
+                } else {
+                    (a * ln_a - 1.0).exp() / A_LAW
+                }
+            }
+        };
+        x * y.signum()
+    }
     
-    // BUILD 023: Granular synthesis
-    grain_counter: f32,
-    grain_phase: f32,
-    grain_envelope: f32,
-    noise_seed: u32,
+    fn process(&mut self, input: f32, bit_depth: f32) -> f32 {
+        let steps = 2.0_f32.powf(bit_depth);
+        let dither = match self.dither {
+            CrushDither::None => 0.0,
+            CrushDither::Rectangular => self.random(),
+            CrushDither::Triangular => self.random() + self.random(),
+        };
+        let compressed = self.compress(input);
+        let quantised = (compressed * steps + dither).round() / steps;
+        self.expand(quantised)
+    }
 }
 
-impl Voice {
+/// BUILD 024: Sample-rate reducer (sample & hold with optional clock jitter)
+struct Decimator {
+    phase: f32,
+    held: f32,
+    rate_hz: f32,
+    jitter: f32,
+    next_jitter: f32,
+    seed: u32,
+    sample_rate: f32,
+}
+
+impl Decimator {
     fn new(sample_rate: f32) -> Self {
         Self {
-            active: false,
-            note_id: -1,
-            frequency: 440.0,
-            phase: 0.0,
-            mod_phase: 0.0,
-            mod_index_base: 2.0,
+            phase: 1.0,
+            held: 0.0,
+            rate_hz: sample_rate,
+            jitter: 0.0,
+            next_jitter: 0.0,
+            seed: 97531,
             sample_rate,
-            
-            master_phase: 0.0,
-            slave_phase: 0.0,
-            sync_amount: 0.0,
-            
-            ring_phase: 0.0,
-            ring_ratio: 1.0,
-            ring_mix: 0.0,
-            
-            wavetable_position: 0.0,
-            
-            feedback: 0.3,
-            last_output: 0.0,
-            
-            envelope_state: EnvelopeState::Idle,
-            env_counter: 0.0,
-            current_level: 0.0,
-            attack_ms: 5.0,
-            decay_ms: 200.0,
-            sustain: 0.7,
-            release_ms: 300.0,
-            
-            mod_env_counter: 0.0,
-            mod_env_level: 0.0,
-            
-            lfo: LFO::new(sample_rate),
-            sample_hold: SampleHold::new(sample_rate),
-            jitter: Jitter::new(sample_rate),
-            
-            cutoff: 2000.0,
-            resonance: 0.5,
-            fold_amount: 2.0,
-            bit_depth: 12.0,
-            
-            filter: SVFilter::new(sample_rate),
-            tilt_eq: TiltEQ::new(),
-            chorus: Chorus::new(sample_rate),
-            comb_filter: CombFilter::new(sample_rate),
-            
-            comb_mix: 0.0,
-            comb_freq: 200.0,
-            comb_feedback: 0.5,
-            comb_damp: 0.5,
-            
-            grain_counter: 0.0,
-            grain_phase: 0.0,
-            grain_envelope: 0.0,
-            noise_seed: 123456,
         }
     }
     
-    pub fn note_on(&mut self, note_id: i32, freq: f32, mod_idx: f32) {
-        self.active = true;
-        self.note_id = note_id;
-        self.frequency = freq;
-        self.mod_index_base = mod_idx;
-        self.envelope_state = EnvelopeState::Attack;
-        self.env_counter = 0.0;
-        self.mod_env_counter = 0.0;
-        
-        self.lfo.reset();
-        self.sample_hold.reset();
-        self.comb_filter.reset();
+    fn set_rate(&mut self, rate_hz: f32, jitter: f32) {
+        self.rate_hz = rate_hz.max(100.0).min(self.sample_rate);
+        self.jitter = jitter.max(0.0).min(1.0);
     }
     
-    pub fn note_off(&mut self, note_id: i32) -> bool {
-        if self.note_id == note_id {
-            self.envelope_state = EnvelopeState::Release;
-            self.env_counter = 0.0;
-            true
-        } else {
-            false
+    fn process(&mut self, input: f32) -> f32 {
+        if self.rate_hz >= self.sample_rate {
+            return input;
+        }
+        
+        self.phase += self.rate_hz / self.sample_rate;
+        if self.phase >= 1.0 + self.next_jitter {
+            self.phase -= 1.0 + self.next_jitter;
+            self.held = input;
+            
+            let mut x = self.seed;
+            x ^= x << 13;
+            x ^= x >> 17;
+            x ^= x << 5;
+            self.seed = x;
+            // Jitter stretches or shortens the next hold period by up to ±50%
+            self.next_jitter = ((x as f32 / u32::MAX as f32) - 0.5) * self.jitter;
+        }
+        
+        self.held
+    }
+}
+
+/// BUILD 024: 4-point Hermite read from a circular buffer, `delay` in samples (>= 2.0)
+fn read_fractional(buffer: &[f32], write_pos: usize, delay: f32) -> f32 {
+    let len = buffer.len();
+    let pos = write_pos as f32 + len as f32 - delay;
+    let index = pos.floor();
+    let t = pos - index;
+    let i0 = index as usize % len;
+    
+    let xm1 = buffer[(i0 + len - 1) % len];
+    let x0 = buffer[i0];
+    let x1 = buffer[(i0 + 1) % len];
+    let x2 = buffer[(i0 + 2) % len];
+    
+    let c1 = 0.5 * (x1 - xm1);
+    let c2 = xm1 - 2.5 * x0 + 2.0 * x1 - 0.5 * x2;
+    let c3 = 0.5 * (x2 - xm1) + 1.5 * (x0 - x1);
+    ((c3 * t + c2) * t + c1) * t + x0
+}
+
+/// BUILD 024: Circular delay line; `read` is fractional (Hermite), `tap` is integer
+struct DelayLine {
+    buffer: Vec<f32>,
+    write_pos: usize,
+}
+
+impl DelayLine {
+    fn new(max_delay_samples: usize) -> Self {
+        Self {
+            buffer: vec![0.0; max_delay_samples + 4],
+            write_pos: 0,
         }
     }
     
-    pub fn force_stop(&mut self) {
-        self.active = false;
-        self.envelope_state = EnvelopeState::Idle;
-        self.current_level = 0.0;
+    fn read(&self, delay: f32) -> f32 {
+        let max_delay = (self.buffer.len() - 3) as f32;
+        read_fractional(&self.buffer, self.write_pos, delay.max(2.0).min(max_delay))
     }
     
-    pub fn is_active(&self) -> bool {
-        self.active
+    fn tap(&self, delay: usize) -> f32 {
+        let len = self.buffer.len();
+        self.buffer[(self.write_pos + len - delay.max(1).min(len - 1)) % len]
     }
     
-    pub fn get_note_id(&self) -> i32 {
-        self.note_id
+    fn write(&mut self, input: f32) {
+        self.buffer[self.write_pos] = flush_denormal(input);
+        self.write_pos = (self.write_pos + 1) % self.buffer.len();
     }
     
-    pub fn set_adsr(&mut self, attack_ms: f32, decay_ms: f32, sustain: f32, release_ms: f32) {
-        self.attack_ms = attack_ms.max(0.1).min(5000.0);
-        self.decay_ms = decay_ms.max(0.1).min(5000.0);
-        self.sustain = sustain.max(0.0).min(1.0);
-        self.release_ms = release_ms.max(0.1).min(10000.0);
+    /// Schroeder allpass around the line: w = x + g·d, y = d − g·w
+    fn allpass(&mut self, input: f32, delay: f32, gain: f32) -> f32 {
+        let delayed = self.read(delay);
+        let w = input + gain * delayed;
+        self.write(w);
+        delayed - gain * w
     }
     
-    pub fn set_fold_amount(&mut self, amount: f32) {
-        self.fold_amount = amount.max(1.0).min(10.0);
+    fn clear(&mut self) {
+        self.buffer.fill(0.0);
+    }
+}
+
+/// BUILD 024: Comb filter structure
+#[derive(Clone, Copy, PartialEq)]
+pub enum CombMode {
+    Feedback = 0,
+    Allpass = 1,
+}
+
+impl CombMode {
+    fn from_index(index: usize) -> Self {
+        match index {
+            1 => CombMode::Allpass,
+            _ => CombMode::Feedback,
+        }
+    }
+}
+
+/// BUILD 023: Enhanced Comb Filter with tanh in feedback loop
+/// BUILD 024: Fractional delay, negative feedback polarity and an allpass-comb variant
+struct CombFilter {
+    buffer: Vec<f32>,
+    write_pos: usize,
+    damping_state: f32,
+    sample_rate: f32,
+    mode: CombMode,
+    negative: bool,
+}
+
+impl CombFilter {
+    fn new(sample_rate: f32) -> Self {
+        // 50 ms reaches down to 20 Hz for key tracking
+        let max_delay_ms = 50.0;
+        let buffer_size = ((max_delay_ms / 1000.0) * sample_rate) as usize + 4;
+        
+        Self {
+            buffer: vec![0.0; buffer_size],
+            write_pos: 0,
+            damping_state: 0.0,
+            sample_rate,
+            mode: CombMode::Feedback,
+            negative: false,
+        }
     }
     
-    pub fn set_bit_depth(&mut self, depth: f32) {
-        self.bit_depth = depth.max(1.0).min(16.0);
+    fn process(&mut self, input: f32, freq: f32, feedback: f32, damp: f32) -> f32 {
+        // BUILD 023: Exponential frequency mapping (50Hz - 1000Hz)
+        let freq_exp = 50.0 * (20.0_f32).powf(freq / 1000.0);
+        let freq_clamped = freq_exp.max(50.0).min(1000.0);
+        self.process_hz(input, freq_clamped, feedback, damp)
     }
     
-    pub fn set_cutoff(&mut self, freq: f32) {
-        self.cutoff = freq.max(20.0).min(20000.0);
+    fn process_hz(&mut self, input: f32, freq_hz: f32, feedback: f32, damp: f32) -> f32 {
+        let max_delay = (self.buffer.len() - 3) as f32;
+        let delay_samples = (self.sample_rate / freq_hz.max(1.0)).max(2.0).min(max_delay);
+        let delayed = read_fractional(&self.buffer, self.write_pos, delay_samples);
+        
+        // Damping filter
+        let damp_coeff = 1.0 - damp.max(0.0).min(1.0);
+        self.damping_state = flush_denormal(self.damping_state + (delayed - self.damping_state) * damp_coeff);
+        
+        // BUILD 023: tanh in feedback loop to prevent divergence
+        let feedback_clamped = feedback.max(0.0).min(0.99);
+        // Negative polarity cancels even harmonics (half-period resonance)
+        let g = if self.negative { -feedback_clamped } else { feedback_clamped };
+        
+        let output = match self.mode {
+            CombMode::Feedback => {
+                self.buffer[self.write_pos] = flush_denormal(input + (self.damping_state * g).tanh());
+                self.damping_state
+            }
+            CombMode::Allpass => {
+                // Schroeder allpass around the delay: flat magnitude, metallic ringing
+                let w = input + (self.damping_state * g).tanh();
+                self.buffer[self.write_pos] = flush_denormal(w);
+                self.damping_state - g * w
+            }
+        };
+        self.write_pos = (self.write_pos + 1) % self.buffer.len();
+        
+        output
     }
     
-    pub fn set_resonance(&mut self, res: f32) {
-        self.resonance = res.max(0.0).min(1.0);
+    fn reset(&mut self) {
+        self.buffer.fill(0.0);
+        self.damping_state = 0.0;
+        self.write_pos = 0;
     }
+}
+
+/// BUILD 024: Shared per-sample synthesis parameters for the oscillator slots
+struct OscContext {
+    sample_rate: f32,
+    mod_env: f32,
+    mod_index_base: f32,
+    sync_amount: f32,
+    wavetable_position: f32,
+    env_time: f32,
+    fm_ratio: f32,
+    harmonics_count: usize,
+    harmonic_rolloff: f32,
+    phase_dist_amount: f32,
+    vector_x: f32,
+    vector_y: f32,
+    grain_size: f32,
+    grain_density: f32,
+    modal_stiffness: f32,
+    modal_inharmonicity: f32,
+}
+
+/// BUILD 024: Oscillator slot (two per voice)
+struct Oscillator {
+    synth_type: usize,
+    coarse: f32,
+    fine: f32,
+    level: f32,
     
-    pub fn set_feedback(&mut self, fb: f32) {
-        self.feedback = fb.max(0.0).min(0.99);
+    phase: f32,
+    mod_phase: f32,
+    master_phase: f32,
+    slave_phase: f32,
+    last_output: f32,
+    
+    grain_counter: f32,
+    grain_phase: f32,
+    grain_envelope: f32,
+    noise_seed: u32,
+}
+
+impl Oscillator {
+    fn new(level: f32, noise_seed: u32) -> Self {
+        Self {
+            synth_type: 0,
+            coarse: 0.0,
+            fine: 0.0,
+            level,
+            phase: 0.0,
+            mod_phase: 0.0,
+            master_phase: 0.0,
+            slave_phase: 0.0,
+            last_output: 0.0,
+            grain_counter: 0.0,
+            grain_phase: 0.0,
+            grain_envelope: 0.0,
+            noise_seed,
+        }
     }
     
-    pub fn set_sync_amount(&mut self, amount: f32) {
-        self.sync_amount = amount.max(0.0).min(5.0);
+    fn set_tuning(&mut self, coarse: f32, fine: f32) {
+        self.coarse = coarse.max(-48.0).min(48.0).round();
+        self.fine = fine.max(-100.0).min(100.0);
     }
     
-    pub fn set_ring_ratio(&mut self, ratio: f32) {
-        self.ring_ratio = ratio.max(0.5).min(4.0);
+    fn set_level(&mut self, level: f32) {
+        self.level = level.max(0.0).min(1.0);
     }
     
-    pub fn set_ring_mix(&mut self, mix: f32) {
-        self.ring_mix = mix.max(0.0).min(1.0);
-    }
-    
-    pub fn set_comb_mix(&mut self, mix: f32) {
-        self.comb_mix = mix.max(0.0).min(1.0);
-    }
-    
-    pub fn set_comb_freq(&mut self, freq: f32) {
-        self.comb_freq = freq.max(50.0).min(1000.0);
-    }
-    
-    pub fn set_comb_feedback(&mut self, feedback: f32) {
-        self.comb_feedback = feedback.max(0.0).min(0.99);
-    }
-    
-    pub fn set_comb_damp(&mut self, damp: f32) {
-        self.comb_damp = damp.max(0.0).min(1.0);
-    }
-    
-    pub fn set_wavetable_position(&mut self, position: f32) {
-        self.wavetable_position = position.max(0.0).min(1.0);
-    }
-    
-    fn wavefold(&self, input: f32, amount: f32) -> f32 {
-        let x = input * amount;
-        if amount > 1.0 {
-            x - 2.0 * (x * 0.5).floor()
-        } else {
-            input
-        }
-    }
-    
-    fn bitcrush(&self, input: f32) -> f32 {
-        let steps = 2.0_f32.powf(self.bit_depth);
-        (input * steps).round() / steps
-    }
-    
-    fn calculate_envelope(&mut self) -> f32 {
-        let attack_samples = (self.attack_ms / 1000.0 * self.sample_rate).max(1.0);
-        let decay_samples = (self.decay_ms / 1000.0 * self.sample_rate).max(1.0);
-        let release_samples = (self.release_ms / 1000.0 * self.sample_rate).max(1.0);
-        
-        match self.envelope_state {
-            EnvelopeState::Idle => {
-                self.current_level = 0.0;
-                0.0
-            }
-            EnvelopeState::Attack => {
-                if self.env_counter >= attack_samples {
-                    self.envelope_state = EnvelopeState::Decay;
-                    self.env_counter = 0.0;
-                    self.current_level = 1.0;
-                } else {
-                    self.current_level = self.env_counter / attack_samples;
-                }
-                self.env_counter += 1.0;
-                self.current_level
-            }
-            EnvelopeState::Decay => {
-                if self.env_counter >= decay_samples {
-                    self.envelope_state = EnvelopeState::Sustain;
-                    self.current_level = self.sustain;
-                } else {
-                    let progress = self.env_counter / decay_samples;
-                    self.current_level = 1.0 - progress * (1.0 - self.sustain);
-                }
-                self.env_counter += 1.0;
-                self.current_level
-            }
-            EnvelopeState::Sustain => {
-                self.current_level = self.sustain;
-                self.sustain
-            }
-            EnvelopeState::Release => {
-                if self.env_counter >= release_samples {
-                    self.active = false;
-                    self.envelope_state = EnvelopeState::Idle;
-                    self.current_level = 0.0;
-                    0.0
-                } else {
-                    let progress = self.env_counter / release_samples;
-                    self.current_level = self.sustain * (1.0 - progress);
-                    self.env_counter += 1.0;
-                    self.current_level
-                }
-            }
-        }
-    }
-    
-    fn calculate_mod_env(&mut self) -> f32 {
-        let mod_attack = 50.0;
-        let mod_decay = 500.0;
-        let mod_attack_samples = (mod_attack / 1000.0 * self.sample_rate).max(1.0);
-        let mod_decay_samples = (mod_decay / 1000.0 * self.sample_rate).max(1.0);
-        
-        if self.mod_env_counter < mod_attack_samples {
-            self.mod_env_level = self.mod_env_counter / mod_attack_samples;
-        } else if self.mod_env_counter < mod_attack_samples + mod_decay_samples {
-            let decay_progress = (self.mod_env_counter - mod_attack_samples) / mod_decay_samples;
-            self.mod_env_level = 1.0 - decay_progress;
-        } else {
-            self.mod_env_level = 0.0;
-        }
-        
-        self.mod_env_counter += 1.0;
-        self.mod_env_level
+    fn tuning_ratio(&self) -> f32 {
+        2.0_f32.powf((self.coarse + self.fine * 0.01) / 12.0)
     }
     
     // BUILD 023: Noise generator
@@ -590,75 +1258,32 @@ impl Voice {
         x ^= x >> 17;
         x ^= x << 5;
         self.noise_seed = x;
-        (x as f32 / std::u32::MAX as f32) * 2.0 - 1.0
+        (x as f32 / u32::MAX as f32) * 2.0 - 1.0
     }
     
-    pub fn process_sample(&mut self, sub_level: f32, sub_detune: f32, sat_drive: f32, 
-                          sat_mix: f32, tilt: f32, 
-                          lfo_depth: f32, lfo_shape: i32,
-                          sh_depth: f32,
-                          jitter_amount: f32, jitter_band_hz: f32,
-                          chorus_mix: f32, chorus_rate_hz: f32, chorus_depth_ms: f32, chorus_feedback: f32,
-                          lfo_to_cutoff: f32, lfo_to_fold: f32,
-                          sh_to_cutoff: f32, sh_to_fold: f32, sh_to_bit: f32,
-                          jitter_to_pitch: f32,
-                          chaos_lfo_value: f32,
-                          spectral_drift_value: f32,
-                          synth_type: usize,
-                          fm_ratio: f32,
-                          harmonics_count: usize,
-                          harmonic_rolloff: f32,
-                          phase_dist_amount: f32,
-                          vector_x: f32,
-                          vector_y: f32,
-                          grain_size: f32,
-                          grain_density: f32,
-                          modal_stiffness: f32,
-                          modal_inharmonicity: f32,
-                          filter_q: f32,
-                          filter_damping: f32,
-                          filter_drive: f32) -> f32 {
-        if !self.active {
-            return 0.0;
-        }
-        
-        let amp_env = self.calculate_envelope();
-        let mod_env = self.calculate_mod_env();
-        
-        if !self.active {
-            return 0.0;
-        }
-        
-        // === MODULATORS ===
-        let lfo_val = self.lfo.tick(lfo_shape) * lfo_depth;
-        let sh_val = self.sample_hold.tick() * sh_depth;
-        let jitter_val = self.jitter.tick(jitter_band_hz) * jitter_amount;
-        
-        // === MODULATION ROUTING ===
-        let mod_cutoff = self.cutoff * (1.0 + lfo_val * lfo_to_cutoff + sh_val * sh_to_cutoff);
-        let mod_fold = self.fold_amount * (1.0 + lfo_val * lfo_to_fold + sh_val * sh_to_fold);
-        let mod_bit_depth = (self.bit_depth + sh_val * sh_to_bit * 8.0).max(1.0).min(16.0);
-        
-        // BUILD 023: Apply Spectral Drift to frequency
-        let chaos_mod = 1.0 + chaos_lfo_value * 0.02;
-        let drift_mod = 1.0 + spectral_drift_value;
-        let mod_freq = self.frequency * (1.0 + jitter_val * jitter_to_pitch * 0.05) * chaos_mod * drift_mod;
-        
-        // === ANALOG DRIFT ===
+    /// Current phase including analog drift (and optional phase modulation from the other slot)
+    fn drifted_phase(&self, phase_mod: f32) -> f32 {
         let drift = (self.mod_phase * 12.345 + self.phase * 67.89).sin() * 0.0001;
-        let drifted_phase = self.phase + drift;
+        if phase_mod != 0.0 {
+            (self.phase + drift + phase_mod).rem_euclid(1.0)
+        } else {
+            self.phase + drift
+        }
+    }
+    
+    fn render(&mut self, freq: f32, phase_mod: f32, feedback_phase: f32, ctx: &OscContext) -> f32 {
+        let drifted_phase = self.drifted_phase(phase_mod);
         
-        // === WAVE SYNTHESIS ===
-        let mut signal = match synth_type {
+        match self.synth_type {
             0 => {
                 // FM Synthesis with Hard Sync
-                let sync_active = self.sync_amount > 0.0;
+                let sync_active = ctx.sync_amount > 0.0;
                 
                 if sync_active {
                     // BUILD 023: Exponential sync_amount mapping
-                    let sync_ratio = 1.0 + (2.0_f32).powf(self.sync_amount) - 1.0;
-                    let master_inc = mod_freq / self.sample_rate;
-                    let slave_inc = (mod_freq * sync_ratio) / self.sample_rate;
+                    let sync_ratio = 1.0 + (2.0_f32).powf(ctx.sync_amount) - 1.0;
+                    let master_inc = freq / ctx.sample_rate;
+                    let slave_inc = (freq * sync_ratio) / ctx.sample_rate;
                     
                     let old_master = self.master_phase;
                     self.master_phase = (self.master_phase + master_inc).fract();
@@ -669,30 +1294,28 @@ impl Voice {
                     
                     self.slave_phase = (self.slave_phase + slave_inc).fract();
                     
-                    let mod_freq_carrier = mod_freq * fm_ratio;
                     let modulator = (self.mod_phase * 2.0 * PI).sin();
-                    let current_mod_index = self.mod_index_base * (1.0 + mod_env * 4.0);
+                    let current_mod_index = ctx.mod_index_base * (1.0 + ctx.mod_env * 4.0);
                     
                     let carrier_phase = self.slave_phase 
                         + (modulator * current_mod_index / (2.0 * PI))
-                        + (self.last_output * self.feedback);
+                        + feedback_phase;
                     
                     (carrier_phase * 2.0 * PI).sin()
                 } else {
-                    let mod_freq_carrier = mod_freq * fm_ratio;
                     let modulator = (self.mod_phase * 2.0 * PI).sin();
-                    let current_mod_index = self.mod_index_base * (1.0 + mod_env * 4.0);
+                    let current_mod_index = ctx.mod_index_base * (1.0 + ctx.mod_env * 4.0);
                     
                     let carrier_phase = drifted_phase 
                         + (modulator * current_mod_index) 
-                        + (self.last_output * self.feedback);
+                        + feedback_phase;
                     
                     (carrier_phase * 2.0 * PI).sin()
                 }
             }
             1 => {
                 // BUILD 023: Wavetable Synthesis (Sine → Triangle → Saw → Square)
-                let pos = self.wavetable_position;
+                let pos = ctx.wavetable_position;
                 let phase_2pi = drifted_phase * 2.0 * PI;
                 
                 if pos < 0.333 {
@@ -719,10 +1342,10 @@ impl Voice {
                 // Additive Synthesis
                 let mut additive_output = 0.0;
                 let base_phase = drifted_phase * 2.0 * PI;
-                let harmonics = harmonics_count.min(16);
+                let harmonics = ctx.harmonics_count.min(16);
                 
                 for h in 1..=harmonics {
-                    let amplitude = 1.0 / (h as f32).powf(harmonic_rolloff);
+                    let amplitude = 1.0 / (h as f32).powf(ctx.harmonic_rolloff);
                     additive_output += (base_phase * h as f32).sin() * amplitude;
                 }
                 
@@ -730,7 +1353,7 @@ impl Voice {
             }
             3 => {
                 // BUILD 023: Phase Distortion (Casio CZ style)
-                let amount = phase_dist_amount;
+                let amount = ctx.phase_dist_amount;
                 let distorted_phase = if drifted_phase < 0.5 {
                     drifted_phase * (1.0 + amount)
                 } else {
@@ -749,18 +1372,18 @@ impl Voice {
                 let noise = self.noise();
                 
                 // Bilinear interpolation
-                let top = sine * (1.0 - vector_x) + saw * vector_x;
-                let bottom = square * (1.0 - vector_x) + noise * vector_x;
+                let top = sine * (1.0 - ctx.vector_x) + saw * ctx.vector_x;
+                let bottom = square * (1.0 - ctx.vector_x) + noise * ctx.vector_x;
                 
-                top * (1.0 - vector_y) + bottom * vector_y
+                top * (1.0 - ctx.vector_y) + bottom * ctx.vector_y
             }
             5 => {
                 // BUILD 023: Granular Synthesis (simplified)
-                let grain_size_samples = (grain_size / 1000.0 * self.sample_rate).max(10.0);
-                let grain_rate = 1.0 / (grain_size_samples / self.sample_rate);
-                let grains_per_sec = grain_rate * grain_density.max(0.1).min(10.0);
+                let grain_size_samples = (ctx.grain_size / 1000.0 * ctx.sample_rate).max(10.0);
+                let grain_rate = 1.0 / (grain_size_samples / ctx.sample_rate);
+                let grains_per_sec = grain_rate * ctx.grain_density.max(0.1).min(10.0);
                 
-                self.grain_counter += grains_per_sec / self.sample_rate;
+                self.grain_counter += grains_per_sec / ctx.sample_rate;
                 
                 if self.grain_counter >= 1.0 {
                     self.grain_counter = 0.0;
@@ -783,18 +1406,16 @@ impl Voice {
             }
             6 => {
                 // BUILD 023: Modal Synthesis (metallic percussion)
-                let base_freq = mod_freq;
                 let mut modal_output = 0.0;
                 
                 // 6 modal resonators with inharmonic partials
                 for mode in 1..=6 {
-                    let inharmonic_ratio = (mode as f32) * (1.0 + modal_inharmonicity * 0.1 * (mode as f32));
-                    let mode_freq = base_freq * inharmonic_ratio;
+                    let inharmonic_ratio = (mode as f32) * (1.0 + ctx.modal_inharmonicity * 0.1 * (mode as f32));
                     let mode_phase = (drifted_phase * inharmonic_ratio).fract();
                     
                     // Exponential decay per mode
-                    let decay_rate = (1.0 - modal_stiffness * 0.1) * (mode as f32);
-                    let decay_env = (-decay_rate * self.env_counter / self.sample_rate).exp();
+                    let decay_rate = (1.0 - ctx.modal_stiffness * 0.1) * (mode as f32);
+                    let decay_env = (-decay_rate * ctx.env_time / ctx.sample_rate).exp();
                     
                     let mode_signal = (mode_phase * 2.0 * PI).sin();
                     let mode_amplitude = 1.0 / (mode as f32).sqrt();
@@ -805,344 +1426,2770 @@ impl Voice {
                 modal_output / 6.0_f32.sqrt()
             }
             _ => (drifted_phase * 2.0 * PI).sin(),
-        };
+        }
+    }
+    
+    /// Advance phases; returns true when the main phase wrapped (used for osc sync)
+    fn advance(&mut self, freq: f32, ctx: &OscContext) -> bool {
+        let old_phase = self.phase;
+        let phase_inc = freq / ctx.sample_rate;
+        self.phase = (self.phase + phase_inc).fract();
         
-        // === RING MODULATION ===
-        if self.ring_mix > 0.0 {
-            let ring_freq = mod_freq * self.ring_ratio;
-            let ring_inc = ring_freq / self.sample_rate;
-            self.ring_phase = (self.ring_phase + ring_inc).fract();
-            
-            // BUILD 023: Soft square wave for ring oscillator
-            let ring_sine = (self.ring_phase * 2.0 * PI).sin();
-            let ring_osc = (ring_sine * 3.0).tanh();
-            
-            let ring_signal = signal * ring_osc;
-            
-            // BUILD 023: Additional tanh for "dirty" metal sound
-            let ring_output = (ring_signal * 1.5).tanh();
-            
-            signal = signal * (1.0 - self.ring_mix) + ring_output * self.ring_mix;
+        let mod_phase_inc = (freq * ctx.fm_ratio) / ctx.sample_rate;
+        self.mod_phase = (self.mod_phase + mod_phase_inc).fract();
+        
+        self.phase < old_phase
+    }
+    
+    fn reset_phase(&mut self) {
+        self.phase = 0.0;
+    }
+}
+
+/// BUILD 024: Re-orderable per-voice processing stages
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum FxStage {
+    Ring,
+    Sub,
+    Saturation,
+    Filter,
+    Formant,
+    Wavefold,
+    Bitcrush,
+    Comb,
+    Chorus,
+}
+
+impl FxStage {
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "ring" => Some(FxStage::Ring),
+            "sub" => Some(FxStage::Sub),
+            "sat" | "saturation" => Some(FxStage::Saturation),
+            "filter" => Some(FxStage::Filter),
+            "formant" => Some(FxStage::Formant),
+            "fold" | "wavefold" => Some(FxStage::Wavefold),
+            "crush" | "bitcrush" => Some(FxStage::Bitcrush),
+            "comb" => Some(FxStage::Comb),
+            "chorus" => Some(FxStage::Chorus),
+            _ => None,
+        }
+    }
+}
+
+/// BUILD 024: Per-voice stage routing
+/// Route syntax: stages joined by `>` run in series, stages joined by `|` run in parallel
+/// (branches are averaged), e.g. `"ring>sub>fold|comb>filter>crush>chorus"`.
+/// Stages left out of the route are bypassed; the amp envelope always comes last.
+/// The noise oscillator joins after the last ring/sub step (or first when neither is
+/// routed), so it never feeds FM feedback.
+/// `tilt` from older routes is accepted but dropped: the tilt control drives the master EQ.
+#[derive(Clone, Debug)]
+struct FxChain {
+    steps: Vec<Vec<FxStage>>,
+    noise_step: usize,
+    legacy_tilt: bool,
+}
+
+impl FxChain {
+    fn default_order() -> Self {
+        let order = [
+            FxStage::Ring,
+            FxStage::Sub,
+            FxStage::Saturation,
+            FxStage::Filter,
+            FxStage::Formant,
+            FxStage::Wavefold,
+            FxStage::Bitcrush,
+            FxStage::Comb,
+            FxStage::Chorus,
+        ];
+        Self::from_steps(order.iter().map(|stage| vec![*stage]).collect())
+    }
+    
+    fn from_steps(steps: Vec<Vec<FxStage>>) -> Self {
+        let noise_step = steps
+            .iter()
+            .rposition(|step| step.iter().any(|stage| matches!(stage, FxStage::Ring | FxStage::Sub)))
+            .map_or(0, |index| index + 1);
+        Self { steps, noise_step, legacy_tilt: false }
+    }
+    
+    fn parse(route: &str) -> Result<Self, String> {
+        let mut steps = Vec::new();
+        let mut used: Vec<FxStage> = Vec::new();
+        let mut legacy_tilt = false;
+        
+        for step_str in route.split('>') {
+            let mut step = Vec::new();
+            for name in step_str.split('|') {
+                let name = name.trim().to_ascii_lowercase();
+                if name.is_empty() {
+                    return Err(format!("empty stage in route '{}'", route));
+                }
+                if name == "tilt" {
+                    legacy_tilt = true;
+                    continue;
+                }
+                let stage = FxStage::from_name(&name)
+                    .ok_or_else(|| format!("unknown stage '{}' in route '{}'", name, route))?;
+                if used.contains(&stage) {
+                    return Err(format!("stage '{}' appears more than once in route '{}'", name, route));
+                }
+                used.push(stage);
+                step.push(stage);
+            }
+            if !step.is_empty() {
+                steps.push(step);
+            }
+        }
+        
+        let mut chain = Self::from_steps(steps);
+        chain.legacy_tilt = legacy_tilt;
+        Ok(chain)
+    }
+}
+
+/// BUILD 024: Per-sample values shared by the re-orderable stages
+struct StageParams {
+    mod_freq: f32,
+    drifted_phase: f32,
+    sub_level: f32,
+    sub_detune: f32,
+    sat_drive: f32,
+    sat_mix: f32,
+    cutoff: f32,
+    resonance: f32,
+    filter_drive: f32,
+    vowel: f32,
+    fold: f32,
+    bit_depth: f32,
+    chorus_mix: f32,
+    chorus_rate_hz: f32,
+    chorus_depth_ms: f32,
+    chorus_feedback: f32,
+}
+
+/// BUILD 024: Modulatable parameter
+/// `base` is only written by setters; per-sample modulation lands in `value` and never feeds back into `base`.
+#[derive(Clone, Copy)]
+struct ModParam {
+    base: f32,
+    value: f32,
+}
+
+impl ModParam {
+    fn new(base: f32) -> Self {
+        Self { base, value: base }
+    }
+    
+    fn set_base(&mut self, base: f32) {
+        self.base = base;
+        self.value = base;
+    }
+    
+    fn modulate(&mut self, value: f32) -> f32 {
+        self.value = value;
+        self.value
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum EnvelopeState {
+    Idle,
+    Attack,
+    Decay,
+    Sustain,
+    Release,
+}
+
+/// BUILD 024: Filter envelope (linear ADSR; attacks and releases start from the current level)
+struct FilterEnvelope {
+    state: EnvelopeState,
+    counter: f32,
+    level: f32,
+    attack_start: f32,
+    release_start: f32,
+    attack_ms: f32,
+    decay_ms: f32,
+    sustain: f32,
+    release_ms: f32,
+    sample_rate: f32,
+}
+
+impl FilterEnvelope {
+    fn new(sample_rate: f32) -> Self {
+        Self {
+            state: EnvelopeState::Idle,
+            counter: 0.0,
+            level: 0.0,
+            attack_start: 0.0,
+            release_start: 0.0,
+            attack_ms: 5.0,
+            decay_ms: 300.0,
+            sustain: 0.0,
+            release_ms: 300.0,
+            sample_rate,
+        }
+    }
+    
+    fn set_adsr(&mut self, attack_ms: f32, decay_ms: f32, sustain: f32, release_ms: f32) {
+        self.attack_ms = attack_ms.max(0.1).min(5000.0);
+        self.decay_ms = decay_ms.max(0.1).min(5000.0);
+        self.sustain = sustain.max(0.0).min(1.0);
+        self.release_ms = release_ms.max(0.1).min(10000.0);
+    }
+    
+    /// Retriggers ramp up from wherever the envelope is, so legato notes don't click the cutoff
+    fn trigger(&mut self) {
+        self.state = EnvelopeState::Attack;
+        self.counter = 0.0;
+        self.attack_start = self.level;
+    }
+    
+    fn release(&mut self) {
+        if self.state != EnvelopeState::Idle {
+            self.state = EnvelopeState::Release;
+            self.counter = 0.0;
+            self.release_start = self.level;
+        }
+    }
+    
+    fn tick(&mut self) -> f32 {
+        let ms_to_samples = self.sample_rate / 1000.0;
+        
+        match self.state {
+            EnvelopeState::Idle => {
+                self.level = 0.0;
+            }
+            EnvelopeState::Attack => {
+                let attack_samples = (self.attack_ms * ms_to_samples).max(1.0);
+                if self.counter >= attack_samples {
+                    self.state = EnvelopeState::Decay;
+                    self.counter = 0.0;
+                    self.level = 1.0;
+                } else {
+                    self.level = self.attack_start + (1.0 - self.attack_start) * (self.counter / attack_samples);
+                }
+            }
+            EnvelopeState::Decay => {
+                let decay_samples = (self.decay_ms * ms_to_samples).max(1.0);
+                if self.counter >= decay_samples {
+                    self.state = EnvelopeState::Sustain;
+                    self.level = self.sustain;
+                } else {
+                    self.level = 1.0 - (self.counter / decay_samples) * (1.0 - self.sustain);
+                }
+            }
+            EnvelopeState::Sustain => {
+                self.level = self.sustain;
+            }
+            EnvelopeState::Release => {
+                let release_samples = (self.release_ms * ms_to_samples).max(1.0);
+                if self.counter >= release_samples {
+                    self.state = EnvelopeState::Idle;
+                    self.level = 0.0;
+                } else {
+                    self.level = self.release_start * (1.0 - self.counter / release_samples);
+                }
+            }
+        }
+        
+        self.counter += 1.0;
+        self.level
+    }
+}
+
+pub struct Voice {
+    active: bool,
+    note_id: i32,
+    frequency: ModParam,
+    velocity: f32,
+    mod_index_base: f32,
+    sample_rate: f32,
+    
+    // BUILD 024: Dual oscillator slots with cross modulation (osc2 -> osc1)
+    oscillators: [Oscillator; 2],
+    osc_fm_amount: f32,
+    osc_ring_amount: f32,
+    osc_sync: bool,
+    
+    // Hard Sync
+    sync_amount: f32,
+    
+    // Ring Modulation
+    ring_phase: f32,
+    ring_ratio: f32,
+    ring_mix: f32,
+    
+    // BUILD 023: Wavetable
+    wavetable_position: f32,
+    
+    feedback: f32,
+    last_output: f32,
+    
+    envelope_state: EnvelopeState,
+    env_counter: f32,
+    current_level: f32,
+    attack_ms: f32,
+    decay_ms: f32,
+    sustain: f32,
+    release_ms: f32,
+    
+    mod_env_counter: f32,
+    mod_env_level: f32,
+    
+    lfo: LFO,
+    sample_hold: SampleHold,
+    jitter: Jitter,
+    
+    cutoff: ModParam,
+    resonance: f32,
+    fold_amount: ModParam,
+    fold_type: FoldType,
+    fold_bias: f32,
+    fold_dc: DcBlocker,
+    saturator: Saturator,
+    bit_depth: ModParam,
+    crusher: BitCrusher,
+    decimator: Decimator,
+    
+    // BUILD 024: Filter key tracking & envelope
+    key_track: f32,
+    key_track_center_hz: f32,
+    filter_env: FilterEnvelope,
+    filter_env_amount: f32,
+    filter_env_velocity: f32,
+    
+    filter: SVFilter,
+    filter_stage2: SVFilter,
+    ladder: LadderFilter,
+    diode_ladder: DiodeLadderFilter,
+    filter_type: FilterType,
+    filter_mode: FilterMode,
+    filter_morph: f32,
+    filter_slope_24: bool,
+    formant: FormantFilter,
+    chorus: Chorus,
+    comb_filter: CombFilter,
+    
+    comb_mix: f32,
+    comb_freq: f32,
+    comb_feedback: f32,
+    comb_damp: f32,
+    comb_key_track: bool,
+    comb_key_ratio: f32,
+    
+    // BUILD 024: Formant filter
+    formant_mix: f32,
+    formant_vowel: ModParam,
+    formant_voice_set: usize,
+    lfo_to_vowel: f32,
+    sh_to_vowel: f32,
+    
+    // BUILD 024: Noise oscillator (mixed before the filter)
+    noise_osc: NoiseGenerator,
+    noise_osc_level: f32,
+    
+    fx_chain: FxChain,
+}
+
+impl Voice {
+    fn new(sample_rate: f32) -> Self {
+        Self {
+            active: false,
+            note_id: -1,
+            frequency: ModParam::new(440.0),
+            velocity: 1.0,
+            mod_index_base: 2.0,
+            sample_rate,
+            
+            oscillators: [Oscillator::new(1.0, 123456), Oscillator::new(0.0, 654321)],
+            osc_fm_amount: 0.0,
+            osc_ring_amount: 0.0,
+            osc_sync: false,
+            
+            sync_amount: 0.0,
+            
+            ring_phase: 0.0,
+            ring_ratio: 1.0,
+            ring_mix: 0.0,
+            
+            wavetable_position: 0.0,
+            
+            feedback: 0.3,
+            last_output: 0.0,
+            
+            envelope_state: EnvelopeState::Idle,
+            env_counter: 0.0,
+            current_level: 0.0,
+            attack_ms: 5.0,
+            decay_ms: 200.0,
+            sustain: 0.7,
+            release_ms: 300.0,
+            
+            mod_env_counter: 0.0,
+            mod_env_level: 0.0,
+            
+            lfo: LFO::new(sample_rate),
+            sample_hold: SampleHold::new(sample_rate),
+            jitter: Jitter::new(sample_rate),
+            
+            cutoff: ModParam::new(2000.0),
+            resonance: 0.5,
+            fold_amount: ModParam::new(2.0),
+            fold_type: FoldType::Modulo,
+            fold_bias: 0.0,
+            fold_dc: DcBlocker::new(sample_rate),
+            saturator: Saturator::new(sample_rate),
+            crusher: BitCrusher::new(13579),
+            decimator: Decimator::new(sample_rate),
+            bit_depth: ModParam::new(12.0),
+            
+            key_track: 0.0,
+            key_track_center_hz: 261.63,
+            filter_env: FilterEnvelope::new(sample_rate),
+            filter_env_amount: 0.0,
+            filter_env_velocity: 0.0,
+            
+            filter: SVFilter::new(sample_rate),
+            filter_stage2: SVFilter::new(sample_rate),
+            ladder: LadderFilter::new(sample_rate),
+            diode_ladder: DiodeLadderFilter::new(sample_rate),
+            filter_type: FilterType::Svf,
+            filter_mode: FilterMode::Lowpass,
+            filter_morph: 0.0,
+            filter_slope_24: false,
+            formant: FormantFilter::new(sample_rate),
+            chorus: Chorus::new(sample_rate),
+            comb_filter: CombFilter::new(sample_rate),
+            
+            comb_mix: 0.0,
+            comb_freq: 200.0,
+            comb_feedback: 0.5,
+            comb_damp: 0.5,
+            comb_key_track: false,
+            comb_key_ratio: 1.0,
+            
+            formant_mix: 0.0,
+            formant_vowel: ModParam::new(0.0),
+            formant_voice_set: 0,
+            lfo_to_vowel: 0.0,
+            sh_to_vowel: 0.0,
+            
+            noise_osc: NoiseGenerator::new(sample_rate, 246813),
+            noise_osc_level: 0.0,
+            
+            fx_chain: FxChain::default_order(),
+        }
+    }
+    
+    pub fn note_on(&mut self, note_id: i32, freq: f32, mod_idx: f32, velocity: f32) {
+        self.active = true;
+        self.note_id = note_id;
+        self.frequency.set_base(freq);
+        self.mod_index_base = mod_idx;
+        self.velocity = velocity.max(0.0).min(1.0);
+        self.envelope_state = EnvelopeState::Attack;
+        self.env_counter = 0.0;
+        self.mod_env_counter = 0.0;
+        self.filter_env.trigger();
+        
+        self.lfo.reset();
+        self.sample_hold.reset();
+        self.comb_filter.reset();
+    }
+    
+    pub fn note_off(&mut self, note_id: i32) -> bool {
+        if self.note_id == note_id {
+            self.envelope_state = EnvelopeState::Release;
+            self.env_counter = 0.0;
+            self.filter_env.release();
+            true
+        } else {
+            false
+        }
+    }
+    
+    pub fn force_stop(&mut self) {
+        self.active = false;
+        self.envelope_state = EnvelopeState::Idle;
+        self.current_level = 0.0;
+    }
+    
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
+    
+    pub fn get_note_id(&self) -> i32 {
+        self.note_id
+    }
+    
+    pub fn envelope_level(&self) -> f32 {
+        self.current_level
+    }
+    
+    pub fn is_held(&self) -> bool {
+        self.active && self.envelope_state != EnvelopeState::Release
+    }
+    
+    pub fn set_adsr(&mut self, attack_ms: f32, decay_ms: f32, sustain: f32, release_ms: f32) {
+        self.attack_ms = attack_ms.max(0.1).min(5000.0);
+        self.decay_ms = decay_ms.max(0.1).min(5000.0);
+        self.sustain = sustain.max(0.0).min(1.0);
+        self.release_ms = release_ms.max(0.1).min(10000.0);
+    }
+    
+    pub fn set_fold_amount(&mut self, amount: f32) {
+        self.fold_amount.set_base(amount.max(1.0).min(10.0));
+    }
+    
+    pub fn set_fold_type(&mut self, fold_type: FoldType) {
+        self.fold_type = fold_type;
+    }
+    
+    pub fn set_fold_bias(&mut self, bias: f32) {
+        self.fold_bias = bias.max(-1.0).min(1.0);
+    }
+    
+    pub fn set_saturation_model(&mut self, model: SatModel) {
+        self.saturator.set_model(model);
+    }
+    
+    pub fn set_chorus_mode(&mut self, mode: ChorusMode, taps: usize, through_zero: bool) {
+        self.chorus.set_mode(mode, taps, through_zero);
+    }
+    
+    pub fn set_saturation_character(&mut self, bias: f32, tone: f32) {
+        self.saturator.set_character(bias, tone);
+    }
+    
+    pub fn set_bit_depth(&mut self, depth: f32) {
+        self.bit_depth.set_base(depth.max(1.0).min(16.0));
+    }
+    
+    pub fn set_decimator(&mut self, rate_hz: f32, jitter: f32) {
+        self.decimator.set_rate(rate_hz, jitter);
+    }
+    
+    pub fn set_crush_curve(&mut self, curve: CrushCurve) {
+        self.crusher.curve = curve;
+    }
+    
+    pub fn set_crush_dither(&mut self, dither: CrushDither) {
+        self.crusher.dither = dither;
+    }
+    
+    pub fn set_cutoff(&mut self, freq: f32) {
+        self.cutoff.set_base(freq.max(20.0).min(20000.0));
+    }
+    
+    pub fn set_resonance(&mut self, res: f32) {
+        self.resonance = res.max(0.0).min(1.0);
+    }
+    
+    pub fn set_feedback(&mut self, fb: f32) {
+        self.feedback = fb.max(0.0).min(0.99);
+    }
+    
+    pub fn set_filter_key_track(&mut self, amount: f32, center_hz: f32) {
+        self.key_track = amount.max(0.0).min(1.0);
+        self.key_track_center_hz = center_hz.max(20.0).min(20000.0);
+    }
+    
+    pub fn set_filter_env(&mut self, attack_ms: f32, decay_ms: f32, sustain: f32, release_ms: f32) {
+        self.filter_env.set_adsr(attack_ms, decay_ms, sustain, release_ms);
+    }
+    
+    pub fn set_filter_env_amount(&mut self, amount: f32, velocity_sens: f32) {
+        self.filter_env_amount = amount.max(-1.0).min(1.0);
+        self.filter_env_velocity = velocity_sens.max(0.0).min(1.0);
+    }
+    
+    pub fn set_filter_type(&mut self, filter_type: FilterType) {
+        self.filter_type = filter_type;
+    }
+    
+    pub fn set_filter_mode(&mut self, mode: FilterMode) {
+        self.filter_mode = mode;
+    }
+    
+    pub fn set_filter_morph(&mut self, morph: f32) {
+        self.filter_morph = morph.max(0.0).min(1.0);
+    }
+    
+    pub fn set_filter_slope_24(&mut self, slope_24: bool) {
+        self.filter_slope_24 = slope_24;
+    }
+    
+    pub fn set_sync_amount(&mut self, amount: f32) {
+        self.sync_amount = amount.max(0.0).min(5.0);
+    }
+    
+    pub fn set_ring_ratio(&mut self, ratio: f32) {
+        self.ring_ratio = ratio.max(0.5).min(4.0);
+    }
+    
+    pub fn set_ring_mix(&mut self, mix: f32) {
+        self.ring_mix = mix.max(0.0).min(1.0);
+    }
+    
+    pub fn set_comb_mix(&mut self, mix: f32) {
+        self.comb_mix = mix.max(0.0).min(1.0);
+    }
+    
+    pub fn set_comb_freq(&mut self, freq: f32) {
+        self.comb_freq = freq.max(50.0).min(1000.0);
+    }
+    
+    pub fn set_comb_feedback(&mut self, feedback: f32) {
+        self.comb_feedback = feedback.max(0.0).min(0.99);
+    }
+    
+    pub fn set_comb_damp(&mut self, damp: f32) {
+        self.comb_damp = damp.max(0.0).min(1.0);
+    }
+    
+    pub fn set_comb_mode(&mut self, mode: CombMode, negative: bool) {
+        self.comb_filter.mode = mode;
+        self.comb_filter.negative = negative;
+    }
+    
+    pub fn set_comb_key_track(&mut self, enabled: bool, semitones: f32) {
+        self.comb_key_track = enabled;
+        self.comb_key_ratio = 2.0_f32.powf(semitones.max(-24.0).min(24.0) / 12.0);
+    }
+    
+    pub fn set_wavetable_position(&mut self, position: f32) {
+        self.wavetable_position = position.max(0.0).min(1.0);
+    }
+    
+    pub fn set_formant(&mut self, mix: f32, vowel: f32, voice_set: usize) {
+        self.formant_mix = mix.max(0.0).min(1.0);
+        self.formant_vowel.set_base(vowel.max(0.0).min(4.0));
+        self.formant_voice_set = voice_set.min(2);
+    }
+    
+    pub fn set_formant_mod(&mut self, lfo_to_vowel: f32, sh_to_vowel: f32) {
+        self.lfo_to_vowel = lfo_to_vowel.max(0.0).min(1.0);
+        self.sh_to_vowel = sh_to_vowel.max(0.0).min(1.0);
+    }
+    
+    pub fn set_osc_type(&mut self, slot: usize, synth_type: usize) {
+        if let Some(osc) = self.oscillators.get_mut(slot) {
+            osc.synth_type = synth_type.min(6);
+        }
+    }
+    
+    pub fn set_osc_tuning(&mut self, slot: usize, coarse: f32, fine: f32) {
+        if let Some(osc) = self.oscillators.get_mut(slot) {
+            osc.set_tuning(coarse, fine);
+        }
+    }
+    
+    pub fn set_osc_level(&mut self, slot: usize, level: f32) {
+        if let Some(osc) = self.oscillators.get_mut(slot) {
+            osc.set_level(level);
+        }
+    }
+    
+    pub fn set_osc_cross_mod(&mut self, fm_amount: f32, ring_amount: f32, sync: bool) {
+        self.osc_fm_amount = fm_amount.max(0.0).min(1.0);
+        self.osc_ring_amount = ring_amount.max(0.0).min(1.0);
+        self.osc_sync = sync;
+    }
+    
+    pub fn set_noise_osc_level(&mut self, level: f32) {
+        self.noise_osc_level = level.max(0.0).min(1.0);
+    }
+    
+    pub fn set_noise_osc_type(&mut self, noise_type: NoiseType) {
+        self.noise_osc.set_type(noise_type);
+    }
+    
+    fn set_fx_chain(&mut self, chain: FxChain) {
+        self.fx_chain = chain;
+    }
+    
+    fn wavefold(&self, input: f32, amount: f32) -> f32 {
+        match self.fold_type {
+            FoldType::Modulo => {
+                let x = input * amount;
+                if amount > 1.0 {
+                    x - 2.0 * (x * 0.5).floor()
+                } else {
+                    input
+                }
+            }
+            FoldType::Sine => (input * amount * PI * 0.5).sin(),
+            FoldType::Triangle => triangle_fold(input * amount),
+            FoldType::Serge => {
+                // Three cascaded sine cells share the total gain
+                let stage_gain = amount.max(1.0).powf(1.0 / 3.0);
+                let mut x = input;
+                for _ in 0..3 {
+                    x = (x * stage_gain * PI * 0.5).sin();
+                }
+                x
+            }
+            FoldType::Asymmetric => triangle_fold(input * amount + self.fold_bias * 2.0),
+        }
+    }
+    
+    fn bitcrush(&mut self, input: f32, bit_depth: f32) -> f32 {
+        let decimated = self.decimator.process(input);
+        self.crusher.process(decimated, bit_depth)
+    }
+    
+    fn calculate_envelope(&mut self) -> f32 {
+        let attack_samples = (self.attack_ms / 1000.0 * self.sample_rate).max(1.0);
+        let decay_samples = (self.decay_ms / 1000.0 * self.sample_rate).max(1.0);
+        let release_samples = (self.release_ms / 1000.0 * self.sample_rate).max(1.0);
+        
+        match self.envelope_state {
+            EnvelopeState::Idle => {
+                self.current_level = 0.0;
+                0.0
+            }
+            EnvelopeState::Attack => {
+                if self.env_counter >= attack_samples {
+                    self.envelope_state = EnvelopeState::Decay;
+                    self.env_counter = 0.0;
+                    self.current_level = 1.0;
+                } else {
+                    self.current_level = self.env_counter / attack_samples;
+                }
+                self.env_counter += 1.0;
+                self.current_level
+            }
+            EnvelopeState::Decay => {
+                if self.env_counter >= decay_samples {
+                    self.envelope_state = EnvelopeState::Sustain;
+                    self.current_level = self.sustain;
+                } else {
+                    let progress = self.env_counter / decay_samples;
+                    self.current_level = 1.0 - progress * (1.0 - self.sustain);
+                }
+                self.env_counter += 1.0;
+                self.current_level
+            }
+            EnvelopeState::Sustain => {
+                self.current_level = self.sustain;
+                self.sustain
+            }
+            EnvelopeState::Release => {
+                if self.env_counter >= release_samples {
+                    self.active = false;
+                    self.envelope_state = EnvelopeState::Idle;
+                    self.current_level = 0.0;
+                    0.0
+                } else {
+                    let progress = self.env_counter / release_samples;
+                    self.current_level = self.sustain * (1.0 - progress);
+                    self.env_counter += 1.0;
+                    self.current_level
+                }
+            }
+        }
+    }
+    
+    fn calculate_mod_env(&mut self) -> f32 {
+        let mod_attack = 50.0;
+        let mod_decay = 500.0;
+        let mod_attack_samples = (mod_attack / 1000.0 * self.sample_rate).max(1.0);
+        let mod_decay_samples = (mod_decay / 1000.0 * self.sample_rate).max(1.0);
+        
+        if self.mod_env_counter < mod_attack_samples {
+            self.mod_env_level = self.mod_env_counter / mod_attack_samples;
+        } else if self.mod_env_counter < mod_attack_samples + mod_decay_samples {
+            let decay_progress = (self.mod_env_counter - mod_attack_samples) / mod_decay_samples;
+            self.mod_env_level = 1.0 - decay_progress;
+        } else {
+            self.mod_env_level = 0.0;
+        }
+        
+        self.mod_env_counter += 1.0;
+        self.mod_env_level
+    }
+    
+    // === NOISE OSCILLATOR ===
+    fn noise_osc_sample(&mut self) -> f32 {
+        if self.noise_osc_level > 0.0 {
+            self.noise_osc.tick() * self.noise_osc_level
+        } else {
+            0.0
+        }
+    }
+    
+    fn process_stage(&mut self, stage: FxStage, input: f32, p: &StageParams) -> f32 {
+        let mut signal = input;
+        
+        match stage {
+            FxStage::Ring => {
+                // === RING MODULATION ===
+                if self.ring_mix > 0.0 {
+                    let ring_freq = p.mod_freq * self.ring_ratio;
+                    let ring_inc = ring_freq / self.sample_rate;
+                    self.ring_phase = (self.ring_phase + ring_inc).fract();
+                    
+                    // BUILD 023: Soft square wave for ring oscillator
+                    let ring_sine = (self.ring_phase * 2.0 * PI).sin();
+                    let ring_osc = (ring_sine * 3.0).tanh();
+                    
+                    let ring_signal = signal * ring_osc;
+                    
+                    // BUILD 023: Additional tanh for "dirty" metal sound
+                    let ring_output = (ring_signal * 1.5).tanh();
+                    
+                    signal = signal * (1.0 - self.ring_mix) + ring_output * self.ring_mix;
+                }
+                
+                self.last_output = signal;
+            }
+            FxStage::Sub => {
+                // === SUB OSCILLATOR ===
+                if p.sub_level > 0.0 {
+                    let sub_phase = (p.drifted_phase * 0.5) * 2.0 * PI;
+                    let detune_phase = (p.drifted_phase * 0.5 * (1.0 + p.sub_detune * 0.01)) * 2.0 * PI;
+                    
+                    // BUILD 023: Soft square sub (tanh waveshaping)
+                    let sub1 = (sub_phase.sin() * 5.0).tanh();
+                    let sub2 = (detune_phase.sin() * 5.0).tanh();
+                    let sub_signal = (sub1 + sub2) * 0.5 * p.sub_level;
+                    
+                    signal += sub_signal;
+                }
+            }
+            FxStage::Saturation => {
+                // === SATURATION ===
+                let saturated = self.saturator.process(signal, p.sat_drive);
+                
+                signal = signal * (1.0 - p.sat_mix) + saturated * p.sat_mix;
+            }
+            FxStage::Filter => {
+                // === FILTER ===
+                signal = match self.filter_type {
+                    FilterType::Svf => {
+                        let mut filtered = self.filter.process_mode(signal, p.cutoff, p.resonance, self.filter_mode, self.filter_morph);
+                        if self.filter_slope_24 {
+                            // 24 dB/oct: cascade a second identical stage
+                            filtered = self.filter_stage2.process_mode(filtered, p.cutoff, p.resonance, self.filter_mode, self.filter_morph);
+                        }
+                        filtered
+                    }
+                    FilterType::Ladder => self.ladder.process(signal, p.cutoff, p.resonance),
+                    FilterType::DiodeLadder => self.diode_ladder.process(signal, p.cutoff, p.resonance),
+                };
+                
+                let driven_signal = signal * p.filter_drive;
+                signal = driven_signal.tanh();
+            }
+            FxStage::Formant => {
+                // === FORMANT FILTER ===
+                if self.formant_mix > 0.0 {
+                    let formant = self.formant.process(signal, p.vowel, self.formant_voice_set);
+                    signal = signal * (1.0 - self.formant_mix) + formant * self.formant_mix;
+                }
+            }
+            FxStage::Wavefold => {
+                // === WAVEFOLD ===
+                signal = self.fold_dc.process(self.wavefold(signal, p.fold));
+            }
+            FxStage::Bitcrush => {
+                // === BITCRUSH ===
+                signal = self.bitcrush(signal, p.bit_depth);
+            }
+            FxStage::Comb => {
+                // === COMB FILTER ===
+                if self.comb_mix > 0.0 {
+                    let combed = if self.comb_key_track {
+                        // Tuned to the modulated voice pitch
+                        self.comb_filter.process_hz(signal, p.mod_freq * self.comb_key_ratio, self.comb_feedback, self.comb_damp)
+                    } else {
+                        self.comb_filter.process(signal, self.comb_freq, self.comb_feedback, self.comb_damp)
+                    };
+                    signal = signal * (1.0 - self.comb_mix) + combed * self.comb_mix;
+                }
+            }
+            FxStage::Chorus => {
+                // === CHORUS ===
+                if p.chorus_mix > 0.0 {
+                    signal = self.chorus.process(signal, p.chorus_mix, p.chorus_rate_hz, p.chorus_depth_ms, p.chorus_feedback);
+                }
+            }
+        }
+        
+        signal
+    }
+    
+    pub fn process_sample(&mut self, sub_level: f32, sub_detune: f32, sat_drive: f32, 
+                          sat_mix: f32,
+                          lfo_depth: f32, lfo_shape: i32,
+                          sh_depth: f32,
+                          jitter_amount: f32, jitter_band_hz: f32,
+                          chorus_mix: f32, chorus_rate_hz: f32, chorus_depth_ms: f32, chorus_feedback: f32,
+                          lfo_to_cutoff: f32, lfo_to_fold: f32,
+                          sh_to_cutoff: f32, sh_to_fold: f32, sh_to_bit: f32,
+                          jitter_to_pitch: f32,
+                          chaos_lfo_value: f32,
+                          spectral_drift_value: f32,
+                          fm_ratio: f32,
+                          harmonics_count: usize,
+                          harmonic_rolloff: f32,
+                          phase_dist_amount: f32,
+                          vector_x: f32,
+                          vector_y: f32,
+                          grain_size: f32,
+                          grain_density: f32,
+                          modal_stiffness: f32,
+                          modal_inharmonicity: f32,
+                          filter_damping: f32,
+                          filter_drive: f32) -> f32 {
+        if !self.active {
+            return 0.0;
+        }
+        
+        let amp_env = self.calculate_envelope();
+        let mod_env = self.calculate_mod_env();
+        
+        if !self.active {
+            return 0.0;
+        }
+        
+        // === MODULATORS ===
+        let lfo_val = self.lfo.tick(lfo_shape) * lfo_depth;
+        let sh_val = self.sample_hold.tick() * sh_depth;
+        let jitter_val = self.jitter.tick(jitter_band_hz) * jitter_amount;
+        
+        // === MODULATION ROUTING ===
+        let filter_env_val = self.filter_env.tick();
+        let env_depth = self.filter_env_amount * (1.0 - self.filter_env_velocity + self.filter_env_velocity * self.velocity);
+        let mod_cutoff = self.cutoff.modulate(
+            self.cutoff.base * (1.0 + lfo_val * lfo_to_cutoff + sh_val * sh_to_cutoff)
+                + filter_env_val * env_depth * 20000.0,
+        );
+        let mod_fold = self.fold_amount.modulate(self.fold_amount.base * (1.0 + lfo_val * lfo_to_fold + sh_val * sh_to_fold));
+        let mod_bit_depth = self.bit_depth.modulate((self.bit_depth.base + sh_val * sh_to_bit * 8.0).max(1.0).min(16.0));
+        let mod_vowel = self.formant_vowel.modulate(
+            (self.formant_vowel.base + (lfo_val * self.lfo_to_vowel + sh_val * self.sh_to_vowel) * 2.0).max(0.0).min(4.0),
+        );
+        
+        // BUILD 023: Apply Spectral Drift to frequency
+        let chaos_mod = 1.0 + chaos_lfo_value * 0.02;
+        let drift_mod = 1.0 + spectral_drift_value;
+        let mod_freq = self.frequency.modulate(
+            self.frequency.base * (1.0 + jitter_val * jitter_to_pitch * 0.05) * chaos_mod * drift_mod,
+        );
+        
+        // === WAVE SYNTHESIS ===
+        let ctx = OscContext {
+            sample_rate: self.sample_rate,
+            mod_env,
+            mod_index_base: self.mod_index_base,
+            sync_amount: self.sync_amount,
+            wavetable_position: self.wavetable_position,
+            env_time: self.env_counter,
+            fm_ratio,
+            harmonics_count,
+            harmonic_rolloff,
+            phase_dist_amount,
+            vector_x,
+            vector_y,
+            grain_size,
+            grain_density,
+            modal_stiffness,
+            modal_inharmonicity,
+        };
+        
+        let osc1_freq = mod_freq * self.oscillators[0].tuning_ratio();
+        let osc2_freq = mod_freq * self.oscillators[1].tuning_ratio();
+        let osc2_needed = self.oscillators[1].level > 0.0
+            || self.osc_fm_amount > 0.0
+            || self.osc_ring_amount > 0.0
+            || self.osc_sync;
+        
+        let osc2 = if osc2_needed {
+            let fb = self.oscillators[1].last_output * self.feedback;
+            let out = self.oscillators[1].render(osc2_freq, 0.0, fb, &ctx);
+            self.oscillators[1].last_output = out;
+            out
+        } else {
+            0.0
+        };
+        
+        // Cross modulation: FM (osc2 -> osc1 phase) and ring
+        let osc1_fb = self.last_output * self.feedback;
+        let mut osc1 = self.oscillators[0].render(osc1_freq, osc2 * self.osc_fm_amount, osc1_fb, &ctx);
+        if self.osc_ring_amount > 0.0 {
+            osc1 = osc1 * (1.0 - self.osc_ring_amount) + osc1 * osc2 * self.osc_ring_amount;
+        }
+        
+        let mut signal = osc1 * self.oscillators[0].level + osc2 * self.oscillators[1].level;
+        let drifted_phase = self.oscillators[0].drifted_phase(0.0);
+        
+        // FM feedback taps the source unless the ring stage updates it
+        self.last_output = signal;
+        
+        // === FILTER CUTOFF ===
+        // BUILD 023: Exponential cutoff mapping
+        let cutoff_exp = 20.0 * (1000.0_f32).powf(mod_cutoff / 20000.0);
+        // Key tracking: 1.0 follows the played pitch 1:1 around the centre note
+        let key_scale = if self.key_track > 0.0 {
+            (self.frequency.base / self.key_track_center_hz).powf(self.key_track)
+        } else {
+            1.0
+        };
+        let cutoff_clamped = (cutoff_exp * key_scale).max(20.0).min(20000.0);
+        
+        // Damping pulls resonance down as the cutoff rises (0.0 at 20 Hz → 1.0 at 20 kHz)
+        let cutoff_pos = (cutoff_clamped / 20.0).ln() / 1000.0_f32.ln();
+        let effective_q = self.resonance * (1.0 - filter_damping * 0.5 * cutoff_pos);
+        
+        // === STAGE CHAIN ===
+        let params = StageParams {
+            mod_freq,
+            drifted_phase,
+            sub_level,
+            sub_detune,
+            sat_drive,
+            sat_mix,
+            cutoff: cutoff_clamped,
+            resonance: effective_q,
+            filter_drive,
+            vowel: mod_vowel,
+            fold: mod_fold,
+            bit_depth: mod_bit_depth,
+            chorus_mix,
+            chorus_rate_hz,
+            chorus_depth_ms,
+            chorus_feedback,
+        };
+        
+        let steps = std::mem::take(&mut self.fx_chain.steps);
+        let noise_step = self.fx_chain.noise_step;
+        for (index, step) in steps.iter().enumerate() {
+            if index == noise_step {
+                signal += self.noise_osc_sample();
+            }
+            if let [stage] = step.as_slice() {
+                signal = self.process_stage(*stage, signal, &params);
+            } else {
+                let mut sum = 0.0;
+                for stage in step {
+                    sum += self.process_stage(*stage, signal, &params);
+                }
+                signal = sum / step.len() as f32;
+            }
+        }
+        if noise_step == steps.len() {
+            signal += self.noise_osc_sample();
+        }
+        self.fx_chain.steps = steps;
+        
+        // === ENVELOPE ===
+        signal *= amp_env;
+        
+        // Phase progression (osc2 is the sync master)
+        let osc2_wrapped = osc2_needed && self.oscillators[1].advance(osc2_freq, &ctx);
+        self.oscillators[0].advance(osc1_freq, &ctx);
+        if self.osc_sync && osc2_wrapped {
+            self.oscillators[0].reset_phase();
+        }
+        
+        signal
+    }
+}
+
+#[derive(Clone, Copy)]
+pub enum ChaosMode {
+    Logistic = 0,
+    Lorenz = 1,
+    DoublePendulum = 2,
+}
+
+pub struct ChaosLfo {
+    mode: ChaosMode,
+    rate: f32,
+    x: f32,
+    y: f32,
+    z: f32,
+    theta1: f32,
+    theta2: f32,
+    p1: f32,
+    p2: f32,
+    sample_rate: f32,
+    phase_counter: f32,
+    // BUILD 024: Running mean of the logistic map (its orbit is not centred on 0.5)
+    logistic_mean: f32,
+}
+
+impl ChaosLfo {
+    pub fn new(sample_rate: f32) -> Self {
+        Self {
+            mode: ChaosMode::Logistic,
+            rate: 1.0,
+            x: 0.5,
+            y: 1.0,
+            z: 1.0,
+            theta1: 1.0,
+            theta2: 1.0,
+            p1: 0.0,
+            p2: 0.0,
+            sample_rate,
+            phase_counter: 0.0,
+            logistic_mean: 0.5,
+        }
+    }
+    
+    pub fn set_mode(&mut self, mode: ChaosMode) {
+        self.mode = mode;
+        self.x = 0.5;
+        self.y = 1.0;
+        self.z = 1.0;
+        self.theta1 = 1.0;
+        self.theta2 = 1.0;
+        self.p1 = 0.0;
+        self.p2 = 0.0;
+        self.logistic_mean = 0.5;
+    }
+    
+    pub fn set_rate(&mut self, rate: f32) {
+        self.rate = rate.max(0.1).min(20.0);
+    }
+    
+    pub fn process(&mut self) -> f32 {
+        let samples_per_update = (self.sample_rate / self.rate).max(1.0);
+        
+        if self.phase_counter >= samples_per_update {
+            self.phase_counter = 0.0;
+            
+            match self.mode {
+                ChaosMode::Logistic => {
+                    let r = 3.9;
+                    self.x = r * self.x * (1.0 - self.x);
+                    self.x = self.x.max(0.0).min(1.0);
+                    self.logistic_mean += (self.x - self.logistic_mean) * 0.02;
+                }
+                ChaosMode::Lorenz => {
+                    let dt = 0.01;
+                    let sigma = 10.0;
+                    let rho = 28.0;
+                    let beta = 8.0 / 3.0;
+                    
+                    let dx = sigma * (self.y - self.x);
+                    let dy = self.x * (rho - self.z) - self.y;
+                    let dz = self.x * self.y - beta * self.z;
+                    
+                    self.x += dx * dt;
+                    self.y += dy * dt;
+                    self.z += dz * dt;
+                    
+                    self.x = self.x.max(-50.0).min(50.0);
+                    self.y = self.y.max(-50.0).min(50.0);
+                    self.z = self.z.max(-50.0).min(50.0);
+                }
+                ChaosMode::DoublePendulum => {
+                    let dt = 0.01;
+                    let g = 9.81;
+                    let l1 = 1.0;
+                    let l2 = 1.0;
+                    let m1 = 1.0;
+                    let m2 = 1.0;
+                    
+                    let num1 = -g * (2.0 * m1 + m2) * self.theta1.sin()
+                        - m2 * g * (self.theta1 - 2.0 * self.theta2).sin()
+                        - 2.0 * (self.theta1 - self.theta2).sin() * m2
+                        * (self.p2 * self.p2 * l2 + self.p1 * self.p1 * l1 * (self.theta1 - self.theta2).cos());
+                    let den1 = l1 * (2.0 * m1 + m2 - m2 * (2.0 * (self.theta1 - self.theta2)).cos());
+                    let alpha1 = num1 / den1;
+                    
+                    let num2 = 2.0 * (self.theta1 - self.theta2).sin()
+                        * (self.p1 * self.p1 * l1 * (m1 + m2)
+                           + g * (m1 + m2) * self.theta1.cos()
+                           + self.p2 * self.p2 * l2 * m2 * (self.theta1 - self.theta2).cos());
+                    let den2 = l2 * (2.0 * m1 + m2 - m2 * (2.0 * (self.theta1 - self.theta2)).cos());
+                    let alpha2 = num2 / den2;
+                    
+                    self.p1 += alpha1 * dt;
+                    self.p2 += alpha2 * dt;
+                    self.theta1 += self.p1 * dt;
+                    self.theta2 += self.p2 * dt;
+                    
+                    self.p1 = self.p1.max(-10.0).min(10.0);
+                    self.p2 = self.p2.max(-10.0).min(10.0);
+                }
+            }
+        }
+        
+        self.phase_counter += 1.0;
+        
+        match self.mode {
+            ChaosMode::Logistic => ((self.x - self.logistic_mean) * 2.0).max(-1.0).min(1.0),
+            ChaosMode::Lorenz => (self.x / 50.0).max(-1.0).min(1.0),
+            ChaosMode::DoublePendulum => (self.theta1 / PI).max(-1.0).min(1.0),
+        }
+    }
+}
+
+pub struct SpectralDrift {
+    amount: f32,
+    speed: f32,
+    drift_type: usize,
+    phase: f32,
+    sample_rate: f32,
+}
+
+impl SpectralDrift {
+    pub fn new(sample_rate: f32) -> Self {
+        Self {
+            amount: 0.0,
+            speed: 1.0,
+            drift_type: 0,
+            phase: 0.0,
+            sample_rate,
+        }
+    }
+    
+    pub fn set_amount(&mut self, amount: f32) {
+        self.amount = amount.max(0.0).min(1.0);
+    }
+    
+    pub fn set_speed(&mut self, speed: f32) {
+        self.speed = speed.max(0.1).min(10.0);
+    }
+    
+    pub fn set_type(&mut self, drift_type: usize) {
+        self.drift_type = drift_type;
+    }
+    
+    pub fn process(&mut self) -> f32 {
+        let phase_inc = self.speed / self.sample_rate;
+        self.phase = (self.phase + phase_inc).fract();
+        
+        let modulation = match self.drift_type {
+            0 => (self.phase * 2.0 * PI).sin(),
+            1 => {
+                let lfo = (self.phase * 2.0 * PI * 0.1).sin();
+                lfo * 0.5 + 0.5
+            }
+            _ => (self.phase * 2.0 * PI).sin(),
+        };
+        
+        modulation * self.amount * 0.02
+    }
+}
+
+/// BUILD 024: Diffuser allpass lengths in samples at 48 kHz (primes, decorrelated per channel)
+const DIFFUSER_LEFT: [f32; 4] = [113.0, 337.0, 557.0, 887.0];
+const DIFFUSER_RIGHT: [f32; 4] = [127.0, 359.0, 587.0, 911.0];
+const DIFFUSER_GAINS: [f32; 4] = [0.7, 0.65, 0.6, 0.55];
+const DIFFUSER_MAX_SIZE: f32 = 4.0;
+const DIFFUSER_EXCURSION: f32 = 8.0;
+
+fn next_prime(n: usize) -> usize {
+    let is_prime = |k: usize| k >= 2 && (2..).take_while(|d| d * d <= k).all(|d| !k.is_multiple_of(d));
+    (n.max(2)..).find(|&k| is_prime(k)).unwrap_or(n)
+}
+
+/// BUILD 024: Stereo Schroeder diffuser - four delay-line allpasses per channel with
+/// prime lengths, a size control and slow modulation to break up metallic ringing
+pub struct Diffusion {
+    left: Vec<DelayLine>,
+    right: Vec<DelayLine>,
+    lengths_left: [f32; 4],
+    lengths_right: [f32; 4],
+    sample_rate: f32,
+    lfo_phase: f32,
+    mix: f32,
+    size: f32,
+    modulation: f32,
+}
+
+impl Diffusion {
+    pub fn new(sample_rate: f32) -> Self {
+        let max_scale = sample_rate / 48000.0 * DIFFUSER_MAX_SIZE;
+        let line = |length: f32| DelayLine::new((length * max_scale * 1.1 + DIFFUSER_EXCURSION) as usize + 8);
+        
+        let mut diffusion = Self {
+            left: DIFFUSER_LEFT.iter().map(|&l| line(l)).collect(),
+            right: DIFFUSER_RIGHT.iter().map(|&l| line(l)).collect(),
+            lengths_left: DIFFUSER_LEFT,
+            lengths_right: DIFFUSER_RIGHT,
+            sample_rate,
+            lfo_phase: 0.0,
+            mix: 0.0,
+            size: 1.0,
+            modulation: 0.2,
+        };
+        diffusion.set_size(1.0);
+        diffusion
+    }
+    
+    pub fn set_mix(&mut self, mix: f32) {
+        self.mix = mix.max(0.0).min(1.0);
+    }
+    
+    /// Rescale the allpass lengths, rounding each to the next prime
+    pub fn set_size(&mut self, size: f32) {
+        self.size = size.max(0.1).min(DIFFUSER_MAX_SIZE);
+        let scale = self.sample_rate / 48000.0 * self.size;
+        for i in 0..4 {
+            self.lengths_left[i] = next_prime((DIFFUSER_LEFT[i] * scale) as usize) as f32;
+            self.lengths_right[i] = next_prime((DIFFUSER_RIGHT[i] * scale) as usize) as f32;
+        }
+    }
+    
+    pub fn set_modulation(&mut self, modulation: f32) {
+        self.modulation = modulation.max(0.0).min(1.0);
+    }
+    
+    /// Runs at mix 0 as well, so the all-passes never hold a stale smear
+    pub fn process(&mut self, input: f32) -> (f32, f32) {
+        self.lfo_phase += 0.7 / self.sample_rate;
+        if self.lfo_phase >= 1.0 {
+            self.lfo_phase -= 1.0;
+        }
+        let excursion = DIFFUSER_EXCURSION * self.modulation;
+        
+        let mut left = input;
+        let mut right = input;
+        for (i, &gain) in DIFFUSER_GAINS.iter().enumerate() {
+            // Each stage gets its own LFO phase; right runs in quadrature to the left
+            let phase = (self.lfo_phase + i as f32 * 0.25) * 2.0 * PI;
+            let mod_left = phase.sin() * excursion;
+            let mod_right = phase.cos() * excursion;
+            left = self.left[i].allpass(left, self.lengths_left[i] + mod_left, gain);
+            right = self.right[i].allpass(right, self.lengths_right[i] + mod_right, gain);
+        }
+        
+        let dry = input * (1.0 - self.mix);
+        (dry + left * self.mix, dry + right * self.mix)
+    }
+}
+
+/// BUILD 024: Dattorro plate reverb (delay lengths in samples at 29761 Hz)
+const PLATE_RATE: f32 = 29761.0;
+const PLATE_INPUT_DIFFUSERS: [f32; 4] = [142.0, 107.0, 379.0, 277.0];
+const PLATE_TANK: [f32; 8] = [672.0, 4453.0, 1800.0, 3720.0, 908.0, 4217.0, 2656.0, 3163.0];
+const PLATE_EXCURSION: f32 = 16.0;
+const PLATE_MAX_SIZE: f32 = 2.0;
+const PLATE_MAX_PREDELAY_MS: f32 = 250.0;
+
+/// BUILD 024: Stereo plate reverb with size, pre-delay, damping, tank modulation and freeze
+pub struct Reverb {
+    sample_rate: f32,
+    predelay: DelayLine,
+    input_diffusers: Vec<DelayLine>,
+    tank: Vec<DelayLine>,
+    bandwidth_state: f32,
+    damp_left: f32,
+    damp_right: f32,
+    tank_feedback: f32,
+    lfo_phase: f32,
+    
+    mix: f32,
+    size: f32,
+    decay: f32,
+    predelay_ms: f32,
+    damping: f32,
+    modulation: f32,
+    freeze: bool,
+}
+
+impl Reverb {
+    pub fn new(sample_rate: f32) -> Self {
+        let scale = sample_rate / PLATE_RATE * PLATE_MAX_SIZE;
+        let line = |length: f32| DelayLine::new((length * scale + PLATE_EXCURSION * scale) as usize + 1);
+        
+        Self {
+            sample_rate,
+            predelay: DelayLine::new((PLATE_MAX_PREDELAY_MS / 1000.0 * sample_rate) as usize + 1),
+            input_diffusers: PLATE_INPUT_DIFFUSERS.iter().map(|&l| line(l)).collect(),
+            tank: PLATE_TANK.iter().map(|&l| line(l)).collect(),
+            bandwidth_state: 0.0,
+            damp_left: 0.0,
+            damp_right: 0.0,
+            tank_feedback: 0.0,
+            lfo_phase: 0.0,
+            
+            mix: 0.0,
+            size: 1.0,
+            decay: 0.5,
+            predelay_ms: 10.0,
+            damping: 0.3,
+            modulation: 0.3,
+            freeze: false,
+        }
+    }
+    
+    pub fn set_params(&mut self, mix: f32, size: f32, decay: f32, predelay_ms: f32) {
+        self.mix = mix.max(0.0).min(1.0);
+        self.size = size.max(0.1).min(PLATE_MAX_SIZE);
+        self.decay = decay.max(0.0).min(0.99);
+        self.predelay_ms = predelay_ms.max(0.0).min(PLATE_MAX_PREDELAY_MS);
+    }
+    
+    pub fn set_tone(&mut self, damping: f32, modulation: f32) {
+        self.damping = damping.max(0.0).min(1.0);
+        self.modulation = modulation.max(0.0).min(1.0);
+    }
+    
+    pub fn set_freeze(&mut self, freeze: bool) {
+        self.freeze = freeze;
+    }
+    
+    pub fn reset(&mut self) {
+        self.predelay.clear();
+        for line in self.input_diffusers.iter_mut().chain(self.tank.iter_mut()) {
+            line.clear();
+        }
+        self.bandwidth_state = 0.0;
+        self.damp_left = 0.0;
+        self.damp_right = 0.0;
+        self.tank_feedback = 0.0;
+    }
+    
+    /// The tank keeps running at mix 0; raising the mix brings in the current tail, not an old one
+    pub fn process(&mut self, in_left: f32, in_right: f32) -> (f32, f32) {
+        let input = (in_left + in_right) * 0.5;
+        let scale = self.sample_rate / PLATE_RATE * self.size;
+        let tap = |line: &DelayLine, offset: f32| line.tap((offset * scale) as usize);
+        
+        // Freeze: no new input, lossless tank
+        let (input_gain, decay, damping) = if self.freeze {
+            (0.0, 1.0, 0.0)
+        } else {
+            (1.0, self.decay, self.damping * 0.9)
+        };
+        
+        // === INPUT ===
+        let predelay_samples = self.predelay_ms / 1000.0 * self.sample_rate;
+        self.predelay.write(input * input_gain);
+        let mut x = if predelay_samples < 2.0 { input * input_gain } else { self.predelay.read(predelay_samples) };
+        self.bandwidth_state = flush_denormal(self.bandwidth_state + (x - self.bandwidth_state) * 0.9995);
+        x = self.bandwidth_state;
+        for (i, diffuser) in self.input_diffusers.iter_mut().enumerate() {
+            let gain = if i < 2 { 0.75 } else { 0.625 };
+            x = diffuser.allpass(x, PLATE_INPUT_DIFFUSERS[i] * scale, gain);
+        }
+        
+        // === TANK ===
+        let lfo = (self.lfo_phase * 2.0 * PI).sin();
+        self.lfo_phase += 1.0 / self.sample_rate;
+        if self.lfo_phase >= 1.0 {
+            self.lfo_phase -= 1.0;
+        }
+        let excursion = PLATE_EXCURSION * self.modulation * self.sample_rate / PLATE_RATE;
+        
+        // Left half (fed by the right half's output)
+        let mut left = x + self.tank_feedback;
+        left = self.tank[0].allpass(left, PLATE_TANK[0] * scale + excursion * lfo, -0.7);
+        self.tank[1].write(left);
+        left = self.tank[1].read(PLATE_TANK[1] * scale);
+        self.damp_left = flush_denormal(left * (1.0 - damping) + self.damp_left * damping);
+        left = self.tank[2].allpass(self.damp_left * decay, PLATE_TANK[2] * scale, 0.5);
+        self.tank[3].write(left);
+        let left_out = self.tank[3].read(PLATE_TANK[3] * scale) * decay;
+        
+        // Right half
+        let mut right = x + left_out;
+        right = self.tank[4].allpass(right, PLATE_TANK[4] * scale - excursion * lfo, -0.7);
+        self.tank[5].write(right);
+        right = self.tank[5].read(PLATE_TANK[5] * scale);
+        self.damp_right = flush_denormal(right * (1.0 - damping) + self.damp_right * damping);
+        right = self.tank[6].allpass(self.damp_right * decay, PLATE_TANK[6] * scale, 0.5);
+        self.tank[7].write(right);
+        self.tank_feedback = self.tank[7].read(PLATE_TANK[7] * scale) * decay;
+        
+        // === OUTPUT TAPS ===
+        let t = &self.tank;
+        let wet_left = tap(&t[5], 266.0) + tap(&t[5], 2974.0) - tap(&t[6], 1913.0) + tap(&t[7], 1996.0)
+            - tap(&t[1], 1990.0) - tap(&t[2], 187.0) - tap(&t[3], 1066.0);
+        let wet_right = tap(&t[1], 353.0) + tap(&t[1], 3627.0) - tap(&t[2], 1228.0) + tap(&t[3], 2673.0)
+            - tap(&t[5], 2111.0) - tap(&t[6], 335.0) - tap(&t[7], 121.0);
+        
+        let dry = 1.0 - self.mix;
+        (in_left * dry + wet_left * 0.6 * self.mix, in_right * dry + wet_right * 0.6 * self.mix)
+    }
+}
+
+/// BUILD 024: In-place iterative radix-2 complex FFT (power-of-two sizes)
+struct Fft {
+    size: usize,
+    cos: Vec<f32>,
+    sin: Vec<f32>,
+    bit_reverse: Vec<usize>,
+}
+
+impl Fft {
+    fn new(size: usize) -> Self {
+        let bits = size.trailing_zeros();
+        Self {
+            size,
+            cos: (0..size / 2).map(|i| (2.0 * PI * i as f32 / size as f32).cos()).collect(),
+            sin: (0..size / 2).map(|i| -(2.0 * PI * i as f32 / size as f32).sin()).collect(),
+            bit_reverse: (0..size).map(|i| i.reverse_bits() >> (usize::BITS - bits)).collect(),
+        }
+    }
+    
+    /// Forward transform; `inverse` conjugates the twiddles (the caller scales by 1/size)
+    fn transform(&self, re: &mut [f32], im: &mut [f32], inverse: bool) {
+        for i in 0..self.size {
+            let j = self.bit_reverse[i];
+            if j > i {
+                re.swap(i, j);
+                im.swap(i, j);
+            }
+        }
+        
+        let sign = if inverse { -1.0 } else { 1.0 };
+        let mut len = 2;
+        while len <= self.size {
+            let half = len / 2;
+            let step = self.size / len;
+            for start in (0..self.size).step_by(len) {
+                for k in 0..half {
+                    let (wr, wi) = (self.cos[k * step], self.sin[k * step] * sign);
+                    let (a, b) = (start + k, start + k + half);
+                    let tr = re[b] * wr - im[b] * wi;
+                    let ti = re[b] * wi + im[b] * wr;
+                    re[b] = re[a] - tr;
+                    im[b] = im[a] - ti;
+                    re[a] += tr;
+                    im[a] += ti;
+                }
+            }
+            len *= 2;
+        }
+    }
+}
+
+/// BUILD 024: Convolution partition size; matches the AudioWorklet render quantum
+const CONV_BLOCK: usize = 128;
+/// Floats per prepared partition: bins 0..=B real parts, then the same bins' imaginary parts
+const CONV_PARTITION: usize = 2 * (CONV_BLOCK + 1);
+const CONV_MAX_IR_SECONDS: f32 = 4.0;
+const CONV_MAX_PREDELAY_MS: f32 = 500.0;
+
+/// BUILD 024: Uniformly partitioned overlap-save convolution (one channel)
+/// The wet output carries one partition (128 samples) of latency.
+/// IR and delay-line spectra are stored flat, `CONV_PARTITION` floats per partition, in
+/// buffers sized for the longest IR up front so loading never allocates.
+struct ConvolutionChannel {
+    ir: Vec<f32>,
+    fdl: Vec<f32>,
+    partitions: usize,
+    input: Vec<f32>,
+    output: Vec<f32>,
+    slot: usize,
+}
+
+impl ConvolutionChannel {
+    fn new(max_partitions: usize) -> Self {
+        Self {
+            ir: vec![0.0; max_partitions * CONV_PARTITION],
+            fdl: vec![0.0; max_partitions * CONV_PARTITION],
+            partitions: 0,
+            input: vec![0.0; 2 * CONV_BLOCK],
+            output: vec![0.0; CONV_BLOCK],
+            slot: 0,
+        }
+    }
+    
+    fn capacity(&self) -> usize {
+        self.ir.len()
+    }
+    
+    /// Append the partition spectra of `ir` (zero-padded to `partitions`) to `spectra`
+    fn prepare(fft: &Fft, ir: &[f32], gain: f32, partitions: usize, spectra: &mut Vec<f32>) {
+        let n = 2 * CONV_BLOCK;
+        let mut re = vec![0.0; n];
+        let mut im = vec![0.0; n];
+        for k in 0..partitions {
+            re.fill(0.0);
+            im.fill(0.0);
+            let start = (k * CONV_BLOCK).min(ir.len());
+            let end = (start + CONV_BLOCK).min(ir.len());
+            for (dst, &src) in re.iter_mut().zip(&ir[start..end]) {
+                *dst = src * gain;
+            }
+            fft.transform(&mut re, &mut im, false);
+            // Real signals have conjugate-symmetric spectra: keep bins 0..=B only
+            spectra.extend_from_slice(&re[..=CONV_BLOCK]);
+            spectra.extend_from_slice(&im[..=CONV_BLOCK]);
+        }
+    }
+    
+    /// Copy in prepared spectra (at most `capacity` floats) without allocating
+    fn load(&mut self, spectra: &[f32]) {
+        self.ir[..spectra.len()].copy_from_slice(spectra);
+        self.fdl[..spectra.len()].fill(0.0);
+        self.partitions = spectra.len() / CONV_PARTITION;
+        self.input.fill(0.0);
+        self.output.fill(0.0);
+        self.slot = 0;
+    }
+    
+    /// Convolve the last 2·B input samples
+    fn process_block(&mut self, fft: &Fft, acc_re: &mut [f32], acc_im: &mut [f32]) {
+        let partitions = self.partitions;
+        if partitions == 0 {
+            self.output.fill(0.0);
+            return;
         }
         
-        self.last_output = signal;
+        let slot = self.slot;
+        self.slot = (slot + 1) % partitions;
+        let bins = CONV_BLOCK + 1;
+        acc_re.copy_from_slice(&self.input);
+        acc_im.fill(0.0);
+        fft.transform(acc_re, acc_im, false);
+        let current = &mut self.fdl[slot * CONV_PARTITION..(slot + 1) * CONV_PARTITION];
+        current[..bins].copy_from_slice(&acc_re[..bins]);
+        current[bins..].copy_from_slice(&acc_im[..bins]);
         
-        // === SUB OSCILLATOR ===
-        if sub_level > 0.0 {
-            let sub_phase = (drifted_phase * 0.5) * 2.0 * PI;
-            let detune_phase = (drifted_phase * 0.5 * (1.0 + sub_detune * 0.01)) * 2.0 * PI;
-            
-            // BUILD 023: Soft square sub (tanh waveshaping)
-            let sub1 = (sub_phase.sin() * 5.0).tanh();
-            let sub2 = (detune_phase.sin() * 5.0).tanh();
-            let sub_signal = (sub1 + sub2) * 0.5 * sub_level;
-            
-            signal += sub_signal;
+        // Frequency-domain delay line: spectrum k blocks ago meets IR partition k
+        acc_re.fill(0.0);
+        acc_im.fill(0.0);
+        for k in 0..partitions {
+            let index = (slot + partitions - k) % partitions;
+            let (x_re, x_im) = self.fdl[index * CONV_PARTITION..(index + 1) * CONV_PARTITION].split_at(bins);
+            let (h_re, h_im) = self.ir[k * CONV_PARTITION..(k + 1) * CONV_PARTITION].split_at(bins);
+            let x = x_re.iter().zip(x_im);
+            let h = h_re.iter().zip(h_im);
+            let acc = acc_re[..bins].iter_mut().zip(acc_im[..bins].iter_mut());
+            for ((ar, ai), ((xr, xi), (hr, hi))) in acc.zip(x.zip(h)) {
+                *ar += xr * hr - xi * hi;
+                *ai += xr * hi + xi * hr;
+            }
+        }
+        // Mirror the conjugate half back in for the inverse transform
+        let n = acc_re.len();
+        for bin in 1..CONV_BLOCK {
+            acc_re[n - bin] = acc_re[bin];
+            acc_im[n - bin] = -acc_im[bin];
+        }
+        
+        fft.transform(acc_re, acc_im, true);
+        let scale = 1.0 / acc_re.len() as f32;
+        // Overlap-save: only the second half is free of circular wrap-around
+        for (out, &value) in self.output.iter_mut().zip(&acc_re[CONV_BLOCK..]) {
+            *out = flush_denormal(value * scale);
         }
         
-        // === SATURATION ===
-        let driven = signal * sat_drive;
+        self.input.copy_within(CONV_BLOCK.., 0);
+    }
+}
+
+/// BUILD 024: Stereo convolution with a user impulse response, pre-delay and wet/dry
+/// The partition FFTs run in `prepare` (off the audio thread); `load` only copies spectra in.
+pub struct Convolver {
+    sample_rate: f32,
+    fft: Fft,
+    channels: [ConvolutionChannel; 2],
+    predelay: [DelayLine; 2],
+    predelay_samples: f32,
+    acc_re: Vec<f32>,
+    acc_im: Vec<f32>,
+    pos: usize,
+    mix: f32,
+}
+
+impl Convolver {
+    pub fn new(sample_rate: f32) -> Self {
+        let max_predelay = (CONV_MAX_PREDELAY_MS / 1000.0 * sample_rate) as usize + 1;
+        let max_partitions = ((CONV_MAX_IR_SECONDS * sample_rate) as usize).div_ceil(CONV_BLOCK);
+        Self {
+            sample_rate,
+            fft: Fft::new(2 * CONV_BLOCK),
+            channels: [ConvolutionChannel::new(max_partitions), ConvolutionChannel::new(max_partitions)],
+            predelay: [DelayLine::new(max_predelay), DelayLine::new(max_predelay)],
+            predelay_samples: 0.0,
+            acc_re: vec![0.0; 2 * CONV_BLOCK],
+            acc_im: vec![0.0; 2 * CONV_BLOCK],
+            pos: 0,
+            mix: 0.0,
+        }
+    }
+    
+    pub fn set_params(&mut self, mix: f32, predelay_ms: f32) {
+        self.mix = mix.max(0.0).min(1.0);
+        self.predelay_samples = predelay_ms.max(0.0).min(CONV_MAX_PREDELAY_MS) / 1000.0 * self.sample_rate;
+    }
+    
+    /// Partition spectra for `load`: the left channel's partitions, then the right's.
+    /// An empty `right` reuses `left` for both channels; `normalize` scales to unit energy.
+    pub fn prepare(left: &[f32], right: &[f32], normalize: bool, sample_rate: f32) -> Vec<f32> {
+        let max_len = (CONV_MAX_IR_SECONDS * sample_rate) as usize;
+        let left = &left[..left.len().min(max_len)];
+        let right = if right.is_empty() { left } else { &right[..right.len().min(max_len)] };
         
-        // BUILD 023: Asymmetric distortion
-        let saturated = if driven > 0.0 {
-            driven.tanh()
+        let gain = if normalize {
+            let energy = left.iter().chain(right).map(|x| x * x).sum::<f32>() * 0.5;
+            if energy > 0.0 { 1.0 / energy.sqrt() } else { 1.0 }
         } else {
-            // Harder clipping on negative side
-            (driven * 1.2).tanh() * 0.95
+            1.0
         };
         
-        signal = signal * (1.0 - sat_mix) + saturated * sat_mix;
+        let fft = Fft::new(2 * CONV_BLOCK);
+        let partitions = left.len().max(right.len()).div_ceil(CONV_BLOCK);
+        let mut spectra = Vec::with_capacity(2 * partitions * CONV_PARTITION);
+        ConvolutionChannel::prepare(&fft, left, gain, partitions, &mut spectra);
+        ConvolutionChannel::prepare(&fft, right, gain, partitions, &mut spectra);
+        spectra
+    }
+    
+    /// Load spectra from `prepare` without allocating. Malformed spectra, or spectra longer
+    /// than the 4 s capacity (e.g. prepared at a higher sample rate), clear the IR and return false.
+    pub fn load(&mut self, spectra: &[f32]) -> bool {
+        let valid = spectra.len().is_multiple_of(2 * CONV_PARTITION)
+            && spectra.len() / 2 <= self.channels[0].capacity();
+        let (left, right) = if valid { spectra.split_at(spectra.len() / 2) } else { (&[][..], &[][..]) };
+        self.channels[0].load(left);
+        self.channels[1].load(right);
+        for line in &mut self.predelay {
+            line.clear();
+        }
+        self.pos = 0;
+        valid
+    }
+    
+    pub fn clear(&mut self) {
+        self.load(&[]);
+    }
+    
+    pub fn process(&mut self, left: f32, right: f32) -> (f32, f32) {
+        // Keep running at mix 0 while an IR is loaded so raising the mix never replays stale state
+        if self.channels[0].partitions == 0 {
+            return (left, right);
+        }
         
-        // === FILTER ===
-        // BUILD 023: Exponential cutoff mapping
-        let cutoff_exp = 20.0 * (1000.0_f32).powf(mod_cutoff / 20000.0);
-        let cutoff_clamped = cutoff_exp.max(20.0).min(20000.0);
+        let dry = [left, right];
+        let mut wet = [0.0; 2];
+        for (channel, conv) in self.channels.iter_mut().enumerate() {
+            let line = &mut self.predelay[channel];
+            line.write(dry[channel]);
+            let input = if self.predelay_samples < 2.0 { dry[channel] } else { line.read(self.predelay_samples) };
+            conv.input[CONV_BLOCK + self.pos] = input;
+            wet[channel] = conv.output[self.pos];
+        }
         
-        let effective_q = filter_q * (1.0 + filter_damping * 0.5);
-        signal = self.filter.process(signal, cutoff_clamped, effective_q);
+        self.pos += 1;
+        if self.pos == CONV_BLOCK {
+            self.pos = 0;
+            for conv in &mut self.channels {
+                conv.process_block(&self.fft, &mut self.acc_re, &mut self.acc_im);
+            }
+        }
         
-        let driven_signal = signal * filter_drive;
-        signal = driven_signal.tanh();
+        let dry_gain = 1.0 - self.mix;
+        (left * dry_gain + wet[0] * self.mix, right * dry_gain + wet[1] * self.mix)
+    }
+}
+
+/// BUILD 024: Delay routing
+#[derive(Clone, Copy, PartialEq)]
+pub enum DelayMode {
+    Stereo = 0,
+    PingPong = 1,
+    Mono = 2,
+}
+
+impl DelayMode {
+    fn from_index(index: usize) -> Self {
+        match index {
+            1 => DelayMode::PingPong,
+            2 => DelayMode::Mono,
+            _ => DelayMode::Stereo,
+        }
+    }
+}
+
+/// BUILD 024: Note divisions for tempo sync, in quarter notes
+const DELAY_DIVISIONS: [f32; 9] = [
+    4.0,        // 1/1
+    2.0,        // 1/2
+    1.0,        // 1/4
+    0.5,        // 1/8
+    0.25,       // 1/16
+    1.5,        // 1/4 dotted
+    0.75,       // 1/8 dotted
+    2.0 / 3.0,  // 1/4 triplet
+    1.0 / 3.0,  // 1/8 triplet
+];
+const DELAY_MAX_MS: f32 = 2000.0;
+
+/// BUILD 024: Master delay - stereo/ping-pong/mono, tempo sync, a filtered, saturated and
+/// crushed feedback path, ducking against the dry signal and tape-style wow and glide
+pub struct StereoDelay {
+    sample_rate: f32,
+    left: DelayLine,
+    right: DelayLine,
+    
+    mode: DelayMode,
+    mix: f32,
+    feedback: f32,
+    time_ms: f32,
+    sync: bool,
+    bpm: f32,
+    division: usize,
+    smoothed_samples: f32,
+    
+    // Feedback path
+    low_cut_coeff: f32,
+    high_cut_coeff: f32,
+    low_state: [f32; 2],
+    high_state: [f32; 2],
+    drive: f32,
+    saturators: [Saturator; 2],
+    bit_depth: f32,
+    crushers: [BitCrusher; 2],
+    decimators: [Decimator; 2],
+    
+    duck_amount: f32,
+    duck_env: f32,
+    
+    wow_depth: f32,
+    wow_rate_hz: f32,
+    wow_phase: f32,
+}
+
+impl StereoDelay {
+    pub fn new(sample_rate: f32) -> Self {
+        let max_samples = (DELAY_MAX_MS * 1.05 / 1000.0 * sample_rate) as usize;
+        let mut saturators = [Saturator::new(sample_rate), Saturator::new(sample_rate)];
+        for saturator in &mut saturators {
+            saturator.set_model(SatModel::Tape);
+        }
         
-        // === WAVEFOLD ===
-        signal = self.wavefold(signal, mod_fold);
+        let mut delay = Self {
+            sample_rate,
+            left: DelayLine::new(max_samples),
+            right: DelayLine::new(max_samples),
+            
+            mode: DelayMode::Stereo,
+            mix: 0.0,
+            feedback: 0.4,
+            time_ms: 375.0,
+            sync: false,
+            bpm: 120.0,
+            division: 2,
+            smoothed_samples: 375.0 / 1000.0 * sample_rate,
+            
+            low_cut_coeff: 0.0,
+            high_cut_coeff: 1.0,
+            low_state: [0.0; 2],
+            high_state: [0.0; 2],
+            drive: 0.0,
+            saturators,
+            bit_depth: 16.0,
+            crushers: [BitCrusher::new(24680), BitCrusher::new(13579)],
+            decimators: [Decimator::new(sample_rate), Decimator::new(sample_rate)],
+            
+            duck_amount: 0.0,
+            duck_env: 0.0,
+            
+            wow_depth: 0.0,
+            wow_rate_hz: 0.5,
+            wow_phase: 0.0,
+        };
+        delay.set_feedback_tone(80.0, 8000.0);
+        delay
+    }
+    
+    pub fn set_params(&mut self, mix: f32, time_ms: f32, feedback: f32, mode: DelayMode) {
+        self.mix = mix.max(0.0).min(1.0);
+        self.time_ms = time_ms.max(1.0).min(DELAY_MAX_MS);
+        // Allow slight runaway; the saturator in the loop keeps it bounded
+        self.feedback = feedback.max(0.0).min(1.1);
+        self.mode = mode;
+    }
+    
+    pub fn set_sync(&mut self, enabled: bool, bpm: f32, division: usize) {
+        self.sync = enabled;
+        self.bpm = bpm.max(20.0).min(300.0);
+        self.division = division.min(DELAY_DIVISIONS.len() - 1);
+    }
+    
+    pub fn set_feedback_tone(&mut self, low_cut_hz: f32, high_cut_hz: f32) {
+        let nyquist = self.sample_rate * 0.45;
+        let low = low_cut_hz.max(10.0).min(nyquist);
+        let high = high_cut_hz.max(low).min(nyquist);
+        self.low_cut_coeff = 1.0 - (-2.0 * PI * low / self.sample_rate).exp();
+        self.high_cut_coeff = 1.0 - (-2.0 * PI * high / self.sample_rate).exp();
+    }
+    
+    pub fn set_grime(&mut self, drive: f32, bit_depth: f32, crush_rate_hz: f32) {
+        self.drive = drive.max(0.0).min(1.0);
+        self.bit_depth = bit_depth.max(1.0).min(16.0);
+        for decimator in &mut self.decimators {
+            decimator.set_rate(crush_rate_hz, 0.0);
+        }
+    }
+    
+    pub fn set_duck(&mut self, amount: f32) {
+        self.duck_amount = amount.max(0.0).min(1.0);
+    }
+    
+    pub fn set_modulation(&mut self, depth: f32, rate_hz: f32) {
+        self.wow_depth = depth.max(0.0).min(1.0);
+        self.wow_rate_hz = rate_hz.max(0.05).min(10.0);
+    }
+    
+    fn target_samples(&self) -> f32 {
+        let ms = if self.sync {
+            60000.0 / self.bpm * DELAY_DIVISIONS[self.division]
+        } else {
+            self.time_ms
+        };
+        ms.min(DELAY_MAX_MS) / 1000.0 * self.sample_rate
+    }
+    
+    /// Filter, saturate and crush one channel of the feedback signal
+    fn feedback_path(&mut self, channel: usize, input: f32) -> f32 {
+        self.high_state[channel] = flush_denormal(self.high_state[channel] + (input - self.high_state[channel]) * self.high_cut_coeff);
+        self.low_state[channel] = flush_denormal(self.low_state[channel] + (self.high_state[channel] - self.low_state[channel]) * self.low_cut_coeff);
+        let mut x = self.high_state[channel] - self.low_state[channel];
         
-        // === TILT EQ ===
-        signal = self.tilt_eq.process(signal, tilt, self.sample_rate);
+        if self.drive > 0.0 {
+            let saturated = self.saturators[channel].process(x, 1.0 + self.drive * 9.0);
+            x = x * (1.0 - self.drive) + saturated * self.drive;
+        }
+        if self.bit_depth < 16.0 {
+            let decimated = self.decimators[channel].process(x);
+            x = self.crushers[channel].process(decimated, self.bit_depth);
+        }
         
-        // === BITCRUSH ===
-        self.bit_depth = mod_bit_depth;
-        signal = self.bitcrush(signal);
+        // Soft ceiling so feedback above 1.0 grinds instead of exploding
+        (x * self.feedback).tanh()
+    }
+    
+    /// Echoes are written at mix 0 too, so the lines always hold the recent past
+    pub fn process(&mut self, in_left: f32, in_right: f32) -> (f32, f32) {
+        // Tape glide: time changes bend the pitch rather than jump
+        let target = self.target_samples();
+        self.smoothed_samples += (target - self.smoothed_samples) * (5.0 / self.sample_rate);
         
-        // === COMB FILTER ===
-        if self.comb_mix > 0.0 {
-            let combed = self.comb_filter.process(signal, self.comb_freq, self.comb_feedback, self.comb_damp);
-            signal = signal * (1.0 - self.comb_mix) + combed * self.comb_mix;
+        // Wow: slow pitch wobble, up to 1% of the delay time
+        self.wow_phase += self.wow_rate_hz / self.sample_rate;
+        if self.wow_phase >= 1.0 {
+            self.wow_phase -= 1.0;
         }
+        let wow = (self.wow_phase * 2.0 * PI).sin() * self.wow_depth * 0.01;
+        let delay_left = self.smoothed_samples * (1.0 + wow);
+        let delay_right = self.smoothed_samples * (1.0 - wow);
         
-        // === CHORUS ===
-        if chorus_mix > 0.0 {
-            let chorused = self.chorus.process(signal, chorus_rate_hz, chorus_depth_ms, chorus_feedback);
-            signal = signal * (1.0 - chorus_mix) + chorused * chorus_mix;
-        }
+        let (wet_left, wet_right) = match self.mode {
+            DelayMode::Stereo => {
+                let out_left = self.left.read(delay_left);
+                let out_right = self.right.read(delay_right);
+                let fb_left = self.feedback_path(0, out_left);
+                let fb_right = self.feedback_path(1, out_right);
+                self.left.write(in_left + fb_left);
+                self.right.write(in_right + fb_right);
+                (out_left, out_right)
+            }
+            DelayMode::PingPong => {
+                // Mono input enters on the left and bounces across
+                let out_left = self.left.read(delay_left);
+                let out_right = self.right.read(delay_right);
+                let fb_left = self.feedback_path(0, out_right);
+                let fb_right = self.feedback_path(1, out_left);
+                self.left.write((in_left + in_right) * 0.5 + fb_left);
+                self.right.write(fb_right);
+                (out_left, out_right)
+            }
+            DelayMode::Mono => {
+                let out = self.left.read(delay_left);
+                let fb = self.feedback_path(0, out);
+                self.left.write((in_left + in_right) * 0.5 + fb);
+                (out, out)
+            }
+        };
         
-        // === ENVELOPE ===
-        signal *= amp_env;
+        // Ducking: the dry level pushes the echoes down (fast attack, ~250 ms release)
+        let level = in_left.abs().max(in_right.abs());
+        let coeff = if level > self.duck_env { 0.01 } else { 4.0 / self.sample_rate };
+        self.duck_env = flush_denormal(self.duck_env + (level - self.duck_env) * coeff);
+        let wet_gain = self.mix * (1.0 - self.duck_amount * self.duck_env.min(1.0));
         
-        // Phase progression
-        let phase_inc = mod_freq / self.sample_rate;
-        self.phase = (self.phase + phase_inc).fract();
+        (in_left + wet_left * wet_gain, in_right + wet_right * wet_gain)
+    }
+}
+
+/// BUILD 024: Parametric EQ band shapes
+#[derive(Clone, Copy, PartialEq)]
+pub enum EqBandType {
+    Peak = 0,
+    LowShelf = 1,
+    HighShelf = 2,
+    LowCut = 3,
+    HighCut = 4,
+}
+
+impl EqBandType {
+    fn from_index(index: usize) -> Self {
+        match index {
+            1 => EqBandType::LowShelf,
+            2 => EqBandType::HighShelf,
+            3 => EqBandType::LowCut,
+            4 => EqBandType::HighCut,
+            _ => EqBandType::Peak,
+        }
+    }
+}
+
+/// BUILD 024: RBJ cookbook biquad (transposed direct form II, stereo state)
+#[derive(Clone, Copy)]
+struct Biquad {
+    b0: f32,
+    b1: f32,
+    b2: f32,
+    a1: f32,
+    a2: f32,
+    state: [[f32; 2]; 2],
+}
+
+impl Biquad {
+    fn new() -> Self {
+        Self { b0: 1.0, b1: 0.0, b2: 0.0, a1: 0.0, a2: 0.0, state: [[0.0; 2]; 2] }
+    }
+    
+    fn set(&mut self, band_type: EqBandType, freq: f32, gain_db: f32, q: f32, sample_rate: f32) {
+        let w0 = 2.0 * PI * freq.max(10.0).min(sample_rate * 0.49) / sample_rate;
+        let (sin, cos) = w0.sin_cos();
+        let alpha = sin / (2.0 * q.max(0.1));
+        let a = 10.0_f32.powf(gain_db / 40.0);
+        let sqrt_a_alpha = 2.0 * a.sqrt() * alpha;
         
-        let mod_phase_inc = (mod_freq * fm_ratio) / self.sample_rate;
-        self.mod_phase = (self.mod_phase + mod_phase_inc).fract();
+        let (b0, b1, b2, a0, a1, a2) = match band_type {
+            EqBandType::Peak => (1.0 + alpha * a, -2.0 * cos, 1.0 - alpha * a, 1.0 + alpha / a, -2.0 * cos, 1.0 - alpha / a),
+            EqBandType::LowShelf => (
+                a * ((a + 1.0) - (a - 1.0) * cos + sqrt_a_alpha),
+                2.0 * a * ((a - 1.0) - (a + 1.0) * cos),
+                a * ((a + 1.0) - (a - 1.0) * cos - sqrt_a_alpha),
+                (a + 1.0) + (a - 1.0) * cos + sqrt_a_alpha,
+                -2.0 * ((a - 1.0) + (a + 1.0) * cos),
+                (a + 1.0) + (a - 1.0) * cos - sqrt_a_alpha,
+            ),
+            EqBandType::HighShelf => (
+                a * ((a + 1.0) + (a - 1.0) * cos + sqrt_a_alpha),
+                -2.0 * a * ((a - 1.0) + (a + 1.0) * cos),
+                a * ((a + 1.0) + (a - 1.0) * cos - sqrt_a_alpha),
+                (a + 1.0) - (a - 1.0) * cos + sqrt_a_alpha,
+                2.0 * ((a - 1.0) - (a + 1.0) * cos),
+                (a + 1.0) - (a - 1.0) * cos - sqrt_a_alpha,
+            ),
+            EqBandType::LowCut => ((1.0 + cos) * 0.5, -(1.0 + cos), (1.0 + cos) * 0.5, 1.0 + alpha, -2.0 * cos, 1.0 - alpha),
+            EqBandType::HighCut => ((1.0 - cos) * 0.5, 1.0 - cos, (1.0 - cos) * 0.5, 1.0 + alpha, -2.0 * cos, 1.0 - alpha),
+        };
         
-        signal
+        self.b0 = b0 / a0;
+        self.b1 = b1 / a0;
+        self.b2 = b2 / a0;
+        self.a1 = a1 / a0;
+        self.a2 = a2 / a0;
+    }
+    
+    fn process(&mut self, input: f32, channel: usize) -> f32 {
+        let z = &mut self.state[channel];
+        let output = self.b0 * input + z[0];
+        z[0] = flush_denormal(self.b1 * input - self.a1 * output + z[1]);
+        z[1] = flush_denormal(self.b2 * input - self.a2 * output);
+        output
     }
 }
 
+const EQ_BANDS: usize = 6;
+const EQ_MAX_CUT_STAGES: usize = 4;
+
+/// BUILD 024: One EQ band; cuts cascade up to four Butterworth sections (12-48 dB/oct)
 #[derive(Clone, Copy)]
-pub enum ChaosMode {
-    Logistic = 0,
-    Lorenz = 1,
-    DoublePendulum = 2,
+struct EqBand {
+    enabled: bool,
+    band_type: EqBandType,
+    stages: [Biquad; EQ_MAX_CUT_STAGES],
+    stage_count: usize,
 }
 
-pub struct ChaosLfo {
-    mode: ChaosMode,
-    rate: f32,
-    x: f32,
-    y: f32,
-    z: f32,
-    theta1: f32,
-    theta2: f32,
-    p1: f32,
-    p2: f32,
+impl EqBand {
+    fn new() -> Self {
+        Self {
+            enabled: false,
+            band_type: EqBandType::Peak,
+            stages: [Biquad::new(); EQ_MAX_CUT_STAGES],
+            stage_count: 1,
+        }
+    }
+    
+    fn set(&mut self, band_type: EqBandType, freq: f32, gain_db: f32, q: f32, slope_db: u32, sample_rate: f32) {
+        self.band_type = band_type;
+        let gain_db = gain_db.max(-24.0).min(24.0);
+        match band_type {
+            EqBandType::LowCut | EqBandType::HighCut => {
+                // Butterworth Q per section for an order-2N response; `q` is unused
+                let sections = (slope_db / 12).max(1).min(EQ_MAX_CUT_STAGES as u32) as usize;
+                self.stage_count = sections;
+                for (k, stage) in self.stages.iter_mut().take(sections).enumerate() {
+                    let angle = (2 * k + 1) as f32 * PI / (4 * sections) as f32;
+                    stage.set(band_type, freq, 0.0, 1.0 / (2.0 * angle.cos()), sample_rate);
+                }
+            }
+            _ => {
+                self.stage_count = 1;
+                self.stages[0].set(band_type, freq, gain_db, q, sample_rate);
+            }
+        }
+    }
+    
+    fn process(&mut self, input: f32, channel: usize) -> f32 {
+        self.stages
+            .iter_mut()
+            .take(self.stage_count)
+            .fold(input, |x, stage| stage.process(x, channel))
+    }
+}
+
+/// BUILD 024: Six-band parametric master EQ; the tilt control drives an opposed
+/// low/high shelf pair pivoting at 1 kHz (±6 dB at the extremes) on top of the bands
+pub struct ParametricEq {
     sample_rate: f32,
-    phase_counter: f32,
+    bands: [EqBand; EQ_BANDS],
+    tilt: f32,
+    tilt_low: Biquad,
+    tilt_high: Biquad,
 }
 
-impl ChaosLfo {
+impl ParametricEq {
     pub fn new(sample_rate: f32) -> Self {
         Self {
-            mode: ChaosMode::Logistic,
-            rate: 1.0,
-            x: 0.5,
-            y: 1.0,
-            z: 1.0,
-            theta1: 1.0,
-            theta2: 1.0,
-            p1: 0.0,
-            p2: 0.0,
             sample_rate,
-            phase_counter: 0.0,
+            bands: [EqBand::new(); EQ_BANDS],
+            tilt: 0.0,
+            tilt_low: Biquad::new(),
+            tilt_high: Biquad::new(),
         }
     }
     
-    pub fn set_mode(&mut self, mode: ChaosMode) {
-        self.mode = mode;
-        self.x = 0.5;
-        self.y = 1.0;
-        self.z = 1.0;
-        self.theta1 = 1.0;
-        self.theta2 = 1.0;
-        self.p1 = 0.0;
-        self.p2 = 0.0;
+    pub fn set_band(&mut self, index: usize, band_type: EqBandType, freq: f32, gain_db: f32, q: f32, slope_db: u32) {
+        if let Some(band) = self.bands.get_mut(index) {
+            band.set(band_type, freq, gain_db, q, slope_db, self.sample_rate);
+            band.enabled = true;
+        }
     }
     
-    pub fn set_rate(&mut self, rate: f32) {
-        self.rate = rate.max(0.1).min(20.0);
+    pub fn set_band_enabled(&mut self, index: usize, enabled: bool) {
+        if let Some(band) = self.bands.get_mut(index) {
+            band.enabled = enabled;
+        }
     }
     
-    pub fn process(&mut self) -> f32 {
-        let samples_per_update = (self.sample_rate / self.rate).max(1.0);
-        
-        if self.phase_counter >= samples_per_update {
-            self.phase_counter = 0.0;
-            
-            match self.mode {
-                ChaosMode::Logistic => {
-                    let r = 3.9;
-                    self.x = r * self.x * (1.0 - self.x);
-                    self.x = self.x.max(0.0).min(1.0);
-                }
-                ChaosMode::Lorenz => {
-                    let dt = 0.01;
-                    let sigma = 10.0;
-                    let rho = 28.0;
-                    let beta = 8.0 / 3.0;
-                    
-                    let dx = sigma * (self.y - self.x);
-                    let dy = self.x * (rho - self.z) - self.y;
-                    let dz = self.x * self.y - beta * self.z;
-                    
-                    self.x += dx * dt;
-                    self.y += dy * dt;
-                    self.z += dz * dt;
-                    
-                    self.x = self.x.max(-50.0).min(50.0);
-                    self.y = self.y.max(-50.0).min(50.0);
-                    self.z = self.z.max(-50.0).min(50.0);
-                }
-                ChaosMode::DoublePendulum => {
-                    let dt = 0.01;
-                    let g = 9.81;
-                    let l1 = 1.0;
-                    let l2 = 1.0;
-                    let m1 = 1.0;
-                    let m2 = 1.0;
-                    
-                    let num1 = -g * (2.0 * m1 + m2) * self.theta1.sin()
-                        - m2 * g * (self.theta1 - 2.0 * self.theta2).sin()
-                        - 2.0 * (self.theta1 - self.theta2).sin() * m2
-                        * (self.p2 * self.p2 * l2 + self.p1 * self.p1 * l1 * (self.theta1 - self.theta2).cos());
-                    let den1 = l1 * (2.0 * m1 + m2 - m2 * (2.0 * (self.theta1 - self.theta2)).cos());
-                    let alpha1 = num1 / den1;
-                    
-                    let num2 = 2.0 * (self.theta1 - self.theta2).sin()
-                        * (self.p1 * self.p1 * l1 * (m1 + m2)
-                           + g * (m1 + m2) * self.theta1.cos()
-                           + self.p2 * self.p2 * l2 * m2 * (self.theta1 - self.theta2).cos());
-                    let den2 = l2 * (2.0 * m1 + m2 - m2 * (2.0 * (self.theta1 - self.theta2)).cos());
-                    let alpha2 = num2 / den2;
-                    
-                    self.p1 += alpha1 * dt;
-                    self.p2 += alpha2 * dt;
-                    self.theta1 += self.p1 * dt;
-                    self.theta2 += self.p2 * dt;
-                    
-                    self.p1 = self.p1.max(-10.0).min(10.0);
-                    self.p2 = self.p2.max(-10.0).min(10.0);
-                }
+    /// Positive tilts towards the bass, negative towards the treble. Both shelves sit at the
+    /// pivot, where each is at half gain, so 1 kHz itself stays at 0 dB.
+    pub fn set_tilt(&mut self, tilt: f32) {
+        self.tilt = tilt.max(-1.0).min(1.0);
+        let gain_db = self.tilt * 6.0;
+        self.tilt_low.set(EqBandType::LowShelf, 1000.0, gain_db, 0.707, self.sample_rate);
+        self.tilt_high.set(EqBandType::HighShelf, 1000.0, -gain_db, 0.707, self.sample_rate);
+    }
+    
+    pub fn process(&mut self, left: f32, right: f32) -> (f32, f32) {
+        let mut out = [left, right];
+        for (channel, sample) in out.iter_mut().enumerate() {
+            for band in self.bands.iter_mut().filter(|band| band.enabled) {
+                *sample = band.process(*sample, channel);
+            }
+            if self.tilt != 0.0 {
+                *sample = self.tilt_high.process(self.tilt_low.process(*sample, channel), channel);
             }
         }
-        
-        self.phase_counter += 1.0;
-        
-        match self.mode {
-            ChaosMode::Logistic => (self.x - 0.5) * 2.0,
-            ChaosMode::Lorenz => (self.x / 50.0).max(-1.0).min(1.0),
-            ChaosMode::DoublePendulum => (self.theta1 / PI).max(-1.0).min(1.0),
+        (out[0], out[1])
+    }
+}
+
+/// BUILD 024: One multiband band's processing (saturator + decimator/bit crusher + gain, per channel)
+struct MultibandBand {
+    saturators: [Saturator; 2],
+    crushers: [BitCrusher; 2],
+    decimators: [Decimator; 2],
+    drive: f32,
+    bit_depth: f32,
+    gain: f32,
+}
+
+impl MultibandBand {
+    fn new(sample_rate: f32, seed: u32) -> Self {
+        Self {
+            saturators: [Saturator::new(sample_rate), Saturator::new(sample_rate)],
+            crushers: [BitCrusher::new(seed), BitCrusher::new(seed.wrapping_mul(7919))],
+            decimators: [Decimator::new(sample_rate), Decimator::new(sample_rate)],
+            drive: 0.0,
+            bit_depth: 16.0,
+            gain: 1.0,
+        }
+    }
+    
+    fn set(&mut self, model: SatModel, drive: f32, bit_depth: f32, crush_rate_hz: f32, gain_db: f32) {
+        for saturator in &mut self.saturators {
+            saturator.set_model(model);
+        }
+        for decimator in &mut self.decimators {
+            decimator.set_rate(crush_rate_hz, 0.0);
+        }
+        self.drive = drive.max(0.0).min(1.0);
+        self.bit_depth = bit_depth.max(1.0).min(16.0);
+        self.gain = 10.0_f32.powf(gain_db.max(-60.0).min(12.0) / 20.0);
+    }
+    
+    fn process(&mut self, input: f32, channel: usize) -> f32 {
+        let mut x = input;
+        if self.drive > 0.0 {
+            x = self.saturators[channel].process(x, 1.0 + self.drive * 9.0);
+        }
+        if self.bit_depth < 16.0 {
+            let decimated = self.decimators[channel].process(x);
+            x = self.crushers[channel].process(decimated, self.bit_depth);
         }
+        x * self.gain
     }
 }
 
-pub struct SpectralDrift {
-    amount: f32,
-    speed: f32,
-    drift_type: usize,
-    phase: f32,
+/// BUILD 024: Three-band Linkwitz-Riley (LR4) multiband distortion
+/// The low band runs through an LR4 allpass at the upper crossover so the bands sum flat;
+/// the dry blend uses that flat sum so it shares the crossover phase.
+pub struct Multiband {
     sample_rate: f32,
+    low_split: [Biquad; 4],
+    high_split: [Biquad; 4],
+    low_allpass: [Biquad; 4],
+    bands: [MultibandBand; 3],
+    mix: f32,
 }
 
-impl SpectralDrift {
+impl Multiband {
     pub fn new(sample_rate: f32) -> Self {
-        Self {
-            amount: 0.0,
-            speed: 1.0,
-            drift_type: 0,
-            phase: 0.0,
+        let mut multiband = Self {
             sample_rate,
+            low_split: [Biquad::new(); 4],
+            high_split: [Biquad::new(); 4],
+            low_allpass: [Biquad::new(); 4],
+            bands: [
+                MultibandBand::new(sample_rate, 11),
+                MultibandBand::new(sample_rate, 22),
+                MultibandBand::new(sample_rate, 33),
+            ],
+            mix: 0.0,
+        };
+        multiband.set_crossovers(150.0, 2500.0);
+        multiband
+    }
+    
+    pub fn set_mix(&mut self, mix: f32) {
+        self.mix = mix.max(0.0).min(1.0);
+    }
+    
+    /// Each split is [LP, LP, HP, HP]: two cascaded Butterworth sections per side = LR4
+    fn set_split(split: &mut [Biquad; 4], freq: f32, sample_rate: f32) {
+        for (i, section) in split.iter_mut().enumerate() {
+            let band_type = if i < 2 { EqBandType::HighCut } else { EqBandType::LowCut };
+            section.set(band_type, freq, 0.0, std::f32::consts::FRAC_1_SQRT_2, sample_rate);
         }
     }
     
-    pub fn set_amount(&mut self, amount: f32) {
-        self.amount = amount.max(0.0).min(1.0);
+    pub fn set_crossovers(&mut self, low_hz: f32, high_hz: f32) {
+        let nyquist = self.sample_rate * 0.45;
+        let low = low_hz.max(20.0).min(nyquist * 0.5);
+        let high = high_hz.max(low * 2.0).min(nyquist);
+        Self::set_split(&mut self.low_split, low, self.sample_rate);
+        Self::set_split(&mut self.high_split, high, self.sample_rate);
+        Self::set_split(&mut self.low_allpass, high, self.sample_rate);
     }
     
-    pub fn set_speed(&mut self, speed: f32) {
-        self.speed = speed.max(0.1).min(10.0);
+    pub fn set_band(&mut self, index: usize, model: SatModel, drive: f32, bit_depth: f32, crush_rate_hz: f32, gain_db: f32) {
+        if let Some(band) = self.bands.get_mut(index) {
+            band.set(model, drive, bit_depth, crush_rate_hz, gain_db);
+        }
     }
     
-    pub fn set_type(&mut self, drift_type: usize) {
-        self.drift_type = drift_type;
+    fn split(sections: &mut [Biquad; 4], input: f32, channel: usize) -> (f32, f32) {
+        let low = sections[0].process(input, channel);
+        let high = sections[2].process(input, channel);
+        (sections[1].process(low, channel), sections[3].process(high, channel))
     }
     
-    pub fn process(&mut self) -> f32 {
-        let phase_inc = self.speed / self.sample_rate;
-        self.phase = (self.phase + phase_inc).fract();
-        
-        let modulation = match self.drift_type {
-            0 => (self.phase * 2.0 * PI).sin(),
-            1 => {
-                let lfo = (self.phase * 2.0 * PI * 0.1).sin();
-                lfo * 0.5 + 0.5
-            }
-            _ => (self.phase * 2.0 * PI).sin(),
+    pub fn process(&mut self, left: f32, right: f32) -> (f32, f32) {
+        let mut out = [left, right];
+        for (channel, sample) in out.iter_mut().enumerate() {
+            let (low, rest) = Self::split(&mut self.low_split, *sample, channel);
+            let (mid, high) = Self::split(&mut self.high_split, rest, channel);
+            // LR4 LP + HP at the same frequency is an allpass; apply it to the low band
+            let (ap_low, ap_high) = Self::split(&mut self.low_allpass, low, channel);
+            let low = ap_low + ap_high;
+            // The bands sum to the input through both crossover allpasses; use that as the
+            // dry path so the blend stays phase-aligned instead of comb filtering
+            let dry = low + mid + high;
+            
+            let wet = self.bands[0].process(low, channel)
+                + self.bands[1].process(mid, channel)
+                + self.bands[2].process(high, channel);
+            *sample = dry * (1.0 - self.mix) + wet * self.mix;
+        }
+        // The filters and bands run regardless; at mix 0 pass the input untouched by the allpasses
+        if self.mix <= 0.0 {
+            return (left, right);
+        }
+        (out[0], out[1])
+    }
+}
+
+const HAAS_MAX_MS: f32 = 30.0;
+
+/// BUILD 024: Master stereo imaging - Haas widening, then an M/S matrix with side width
+/// and an LR4 high-pass on the side channel that folds the bass to mono
+pub struct StereoWidth {
+    sample_rate: f32,
+    width: f32,
+    bass_mono: bool,
+    side_highpass: [Biquad; 2],
+    haas: DelayLine,
+    haas_samples: f32,
+    haas_amount: f32,
+}
+
+impl StereoWidth {
+    pub fn new(sample_rate: f32) -> Self {
+        let mut stereo = Self {
+            sample_rate,
+            width: 1.0,
+            bass_mono: false,
+            side_highpass: [Biquad::new(); 2],
+            haas: DelayLine::new((HAAS_MAX_MS / 1000.0 * sample_rate) as usize + 1),
+            haas_samples: 0.0,
+            haas_amount: 0.0,
         };
+        stereo.set_bass_mono(false, 120.0);
+        stereo
+    }
+    
+    /// 0 = mono, 1 = unchanged, 2 = double side level
+    pub fn set_width(&mut self, width: f32) {
+        self.width = width.max(0.0).min(2.0);
+    }
+    
+    pub fn set_bass_mono(&mut self, enabled: bool, freq_hz: f32) {
+        self.bass_mono = enabled;
+        let freq = freq_hz.max(20.0).min(500.0);
+        for section in &mut self.side_highpass {
+            section.set(EqBandType::LowCut, freq, 0.0, std::f32::consts::FRAC_1_SQRT_2, self.sample_rate);
+        }
+    }
+    
+    /// Delays the right channel by up to 30 ms, blended in by `amount`
+    pub fn set_haas(&mut self, delay_ms: f32, amount: f32) {
+        self.haas_samples = delay_ms.max(0.0).min(HAAS_MAX_MS) / 1000.0 * self.sample_rate;
+        self.haas_amount = amount.max(0.0).min(1.0);
+    }
+    
+    pub fn process(&mut self, left: f32, mut right: f32) -> (f32, f32) {
+        // `read` clamps to 2 samples, so shorter times (0 ms included) bypass the line
+        let delayed = if self.haas_samples < 2.0 { right } else { self.haas.read(self.haas_samples) };
+        self.haas.write(right);
+        if self.haas_amount > 0.0 {
+            right = right * (1.0 - self.haas_amount) + delayed * self.haas_amount;
+        }
         
-        modulation * self.amount * 0.02
+        if self.width == 1.0 && !self.bass_mono {
+            return (left, right);
+        }
+        
+        let mid = (left + right) * 0.5;
+        let mut side = (left - right) * 0.5 * self.width;
+        if self.bass_mono {
+            side = self.side_highpass.iter_mut().fold(side, |x, section| section.process(x, 0));
+        }
+        (mid + side, mid - side)
     }
 }
 
-struct AllpassFilter {
-    buffer: f32,
+/// BUILD 024: Compressor level detection
+#[derive(Clone, Copy, PartialEq)]
+pub enum DetectorMode {
+    Peak = 0,
+    Rms = 1,
 }
 
-impl AllpassFilter {
-    fn new() -> Self {
-        Self { buffer: 0.0 }
+impl DetectorMode {
+    fn from_index(index: usize) -> Self {
+        match index {
+            1 => DetectorMode::Rms,
+            _ => DetectorMode::Peak,
+        }
     }
-    
-    fn process(&mut self, input: f32, coeff: f32) -> f32 {
-        let output = -input + self.buffer;
-        self.buffer = input + coeff * output;
-        output
+}
+
+/// BUILD 024: What drives the compressor's detector
+#[derive(Clone, Copy, PartialEq)]
+pub enum SidechainSource {
+    /// The master bus itself
+    Internal = 0,
+    /// Buffer supplied from JS via `set_sidechain_input`
+    External = 1,
+    /// The voice(s) playing a chosen note id, e.g. a kick
+    Note = 2,
+}
+
+impl SidechainSource {
+    fn from_index(index: usize) -> Self {
+        match index {
+            1 => SidechainSource::External,
+            2 => SidechainSource::Note,
+            _ => SidechainSource::Internal,
+        }
     }
 }
 
-pub struct Diffusion {
-    allpass1: AllpassFilter,
-    allpass2: AllpassFilter,
-    allpass3: AllpassFilter,
-    allpass4: AllpassFilter,
+/// BUILD 024: Feed-forward stereo-linked compressor (soft knee, dB-domain attack/release,
+/// makeup and parallel mix); the key signal is passed in so any sidechain source can drive it
+pub struct Compressor {
+    sample_rate: f32,
+    threshold_db: f32,
+    ratio: f32,
+    knee_db: f32,
+    makeup: f32,
     mix: f32,
+    attack_coeff: f32,
+    release_coeff: f32,
+    rms_coeff: f32,
+    detector: DetectorMode,
+    
+    rms_state: f32,
+    reduction_db: f32,
+    max_reduction: f32,
 }
 
-impl Diffusion {
-    pub fn new() -> Self {
-        Self {
-            allpass1: AllpassFilter::new(),
-            allpass2: AllpassFilter::new(),
-            allpass3: AllpassFilter::new(),
-            allpass4: AllpassFilter::new(),
+impl Compressor {
+    pub fn new(sample_rate: f32) -> Self {
+        let mut compressor = Self {
+            sample_rate,
+            threshold_db: -18.0,
+            ratio: 4.0,
+            knee_db: 6.0,
+            makeup: 1.0,
             mix: 0.0,
-        }
+            attack_coeff: 1.0,
+            release_coeff: 1.0,
+            // 10 ms RMS window
+            rms_coeff: 1.0 - (-1.0 / (0.01 * sample_rate)).exp(),
+            detector: DetectorMode::Peak,
+            
+            rms_state: 0.0,
+            reduction_db: 0.0,
+            max_reduction: 0.0,
+        };
+        compressor.set_timing(10.0, 150.0, DetectorMode::Peak);
+        compressor
     }
     
-    pub fn set_mix(&mut self, mix: f32) {
+    pub fn set_params(&mut self, threshold_db: f32, ratio: f32, knee_db: f32, makeup_db: f32, mix: f32) {
+        self.threshold_db = threshold_db.max(-60.0).min(0.0);
+        self.ratio = ratio.max(1.0).min(50.0);
+        self.knee_db = knee_db.max(0.0).min(24.0);
+        self.makeup = 10.0_f32.powf(makeup_db.max(0.0).min(24.0) / 20.0);
         self.mix = mix.max(0.0).min(1.0);
     }
     
-    pub fn process(&mut self, input: f32) -> f32 {
-        if self.mix <= 0.0 {
-            return input;
+    pub fn set_timing(&mut self, attack_ms: f32, release_ms: f32, detector: DetectorMode) {
+        let coeff = |ms: f32| 1.0 - (-1.0 / (ms / 1000.0 * self.sample_rate)).exp();
+        self.attack_coeff = coeff(attack_ms.max(0.05).min(500.0));
+        self.release_coeff = coeff(release_ms.max(5.0).min(5000.0));
+        self.detector = detector;
+    }
+    
+    pub fn take_gain_reduction_db(&mut self) -> f32 {
+        let reduction = self.max_reduction;
+        self.max_reduction = 0.0;
+        reduction
+    }
+    
+    /// Static curve: gain reduction in dB for a detector level in dB
+    fn gain_reduction(&self, level_db: f32) -> f32 {
+        let over = level_db - self.threshold_db;
+        let slope = 1.0 - 1.0 / self.ratio;
+        if 2.0 * over <= -self.knee_db {
+            0.0
+        } else if 2.0 * over.abs() < self.knee_db {
+            let x = over + self.knee_db * 0.5;
+            slope * x * x / (2.0 * self.knee_db)
+        } else {
+            slope * over
+        }
+    }
+    
+    /// The envelope follows the key at mix 0 too, so raising the mix doesn't jump the gain
+    pub fn process(&mut self, left: f32, right: f32, key: f32) -> (f32, f32) {
+        let level = match self.detector {
+            DetectorMode::Peak => key.abs(),
+            DetectorMode::Rms => {
+                self.rms_state = flush_denormal(self.rms_state + (key * key - self.rms_state) * self.rms_coeff);
+                self.rms_state.sqrt()
+            }
+        };
+        let target = self.gain_reduction(20.0 * level.max(1.0e-6).log10());
+        let coeff = if target > self.reduction_db { self.attack_coeff } else { self.release_coeff };
+        self.reduction_db = flush_denormal(self.reduction_db + (target - self.reduction_db) * coeff);
+        if self.mix > 0.0 {
+            self.max_reduction = self.max_reduction.max(self.reduction_db);
+        }
+        
+        let gain = 10.0_f32.powf(-self.reduction_db / 20.0) * self.makeup;
+        let dry = 1.0 - self.mix;
+        (left * dry + left * gain * self.mix, right * dry + right * gain * self.mix)
+    }
+}
+
+/// BUILD 024: Output stage behaviour
+#[derive(Clone, Copy, PartialEq)]
+pub enum LimiterMode {
+    /// BUILD 023 behaviour: ±1 clamp after drive plus the soft-knee tanh limiter
+    Clip = 0,
+    Lookahead = 1,
+}
+
+impl LimiterMode {
+    fn from_index(index: usize) -> Self {
+        match index {
+            0 => LimiterMode::Clip,
+            _ => LimiterMode::Lookahead,
+        }
+    }
+}
+
+const LIMITER_MAX_LOOKAHEAD_MS: f32 = 20.0;
+/// BUILD 024: True-peak detector span: 4x windowed-sinc interpolation over 8 samples. The
+/// interpolated points sit between the 4th and 5th newest samples, so true-peak mode delays
+/// the audio by `TRUE_PEAK_LATENCY` more samples to keep the gain ramp on time.
+const TRUE_PEAK_TAPS: usize = 8;
+const TRUE_PEAK_LATENCY: usize = TRUE_PEAK_TAPS / 2;
+
+/// BUILD 024: Stereo-linked lookahead brickwall limiter
+/// Gain = sliding-window minimum of ceiling/peak, box-smoothed over the lookahead so it lands
+/// on each peak exactly as the delayed audio arrives, then released exponentially.
+pub struct Limiter {
+    sample_rate: f32,
+    delay: [Vec<f32>; 2],
+    gains: Vec<f32>,
+    window: VecDeque<(usize, f32)>,
+    history: [[f32; TRUE_PEAK_TAPS]; 2],
+    true_peak_coeffs: [[f32; TRUE_PEAK_TAPS]; 3],
+    pos: usize,
+    counter: usize,
+    lookahead: usize,
+    delay_len: usize,
+    smooth_sum: f64,
+    gain: f32,
+    
+    ceiling: f32,
+    release_coeff: f32,
+    true_peak: bool,
+    max_reduction: f32,
+}
+
+impl Limiter {
+    pub fn new(sample_rate: f32) -> Self {
+        let max_lookahead = (LIMITER_MAX_LOOKAHEAD_MS / 1000.0 * sample_rate) as usize + 1;
+        let max_delay = max_lookahead + TRUE_PEAK_LATENCY;
+        
+        // Hann-windowed sinc taps for the points at 1/4, 1/2 and 3/4 of the span
+        let mut true_peak_coeffs = [[0.0; TRUE_PEAK_TAPS]; 3];
+        for (phase, coeffs) in true_peak_coeffs.iter_mut().enumerate() {
+            let t = (phase + 1) as f32 * 0.25;
+            for (k, coeff) in coeffs.iter_mut().enumerate() {
+                let x = t - (k as f32 - (TRUE_PEAK_LATENCY - 1) as f32);
+                let window = 0.5 * (1.0 + (PI * x / TRUE_PEAK_LATENCY as f32).cos());
+                *coeff = (PI * x).sin() / (PI * x) * window;
+            }
+            let sum: f32 = coeffs.iter().sum();
+            for coeff in coeffs.iter_mut() {
+                *coeff /= sum;
+            }
+        }
+        
+        let mut limiter = Self {
+            sample_rate,
+            delay: [vec![0.0; max_delay], vec![0.0; max_delay]],
+            gains: vec![1.0; max_lookahead],
+            window: VecDeque::with_capacity(max_lookahead + 1),
+            history: [[0.0; TRUE_PEAK_TAPS]; 2],
+            true_peak_coeffs,
+            pos: 0,
+            counter: 0,
+            lookahead: 1,
+            delay_len: 1,
+            smooth_sum: 1.0,
+            gain: 1.0,
+            
+            ceiling: 0.98,
+            release_coeff: 0.0,
+            true_peak: false,
+            max_reduction: 0.0,
+        };
+        limiter.set_params(5.0, 100.0, -0.2, false);
+        limiter
+    }
+    
+    pub fn set_params(&mut self, lookahead_ms: f32, release_ms: f32, ceiling_db: f32, true_peak: bool) {
+        let max_lookahead = self.gains.len();
+        let lookahead = ((lookahead_ms.max(0.0).min(LIMITER_MAX_LOOKAHEAD_MS) / 1000.0 * self.sample_rate) as usize)
+            .max(1)
+            .min(max_lookahead);
+        if lookahead != self.lookahead || true_peak != self.true_peak {
+            self.lookahead = lookahead;
+            self.true_peak = true_peak;
+            self.delay_len = lookahead + if true_peak { TRUE_PEAK_LATENCY } else { 0 };
+            self.reset();
+        }
+        self.release_coeff = 1.0 - (-1.0 / (release_ms.max(1.0).min(2000.0) / 1000.0 * self.sample_rate)).exp();
+        self.ceiling = 10.0_f32.powf(ceiling_db.max(-24.0).min(0.0) / 20.0);
+    }
+    
+    pub fn reset(&mut self) {
+        for line in &mut self.delay {
+            line.fill(0.0);
+        }
+        self.gains.fill(1.0);
+        self.window.clear();
+        self.history = [[0.0; TRUE_PEAK_TAPS]; 2];
+        self.pos = 0;
+        self.smooth_sum = self.lookahead as f64;
+        self.gain = 1.0;
+        self.max_reduction = 0.0;
+    }
+    
+    /// Largest gain reduction (dB, positive) since the last call
+    pub fn take_gain_reduction_db(&mut self) -> f32 {
+        let reduction = self.max_reduction;
+        self.max_reduction = 0.0;
+        reduction
+    }
+    
+    /// Sample peak, or in true-peak mode the largest of the span's two samples and its three
+    /// interpolated inter-sample points (`TRUE_PEAK_LATENCY` samples behind `input`)
+    fn peak(&mut self, channel: usize, input: f32) -> f32 {
+        let h = &mut self.history[channel];
+        h.rotate_left(1);
+        h[TRUE_PEAK_TAPS - 1] = input;
+        if !self.true_peak {
+            return input.abs();
+        }
+        
+        let h = &self.history[channel];
+        let span = h[TRUE_PEAK_LATENCY - 1].abs().max(h[TRUE_PEAK_LATENCY].abs());
+        self.true_peak_coeffs
+            .iter()
+            .map(|coeffs| coeffs.iter().zip(h).map(|(c, x)| c * x).sum::<f32>().abs())
+            .fold(span, f32::max)
+    }
+    
+    pub fn process(&mut self, left: f32, right: f32) -> (f32, f32) {
+        let peak = self.peak(0, left).max(self.peak(1, right));
+        let target = if peak > self.ceiling { self.ceiling / peak } else { 1.0 };
+        
+        // Sliding minimum over the last `lookahead` targets (monotonic deque)
+        while self.window.back().is_some_and(|&(_, g)| g >= target) {
+            self.window.pop_back();
+        }
+        self.window.push_back((self.counter, target));
+        while self.window.front().is_some_and(|&(i, _)| i + self.lookahead <= self.counter) {
+            self.window.pop_front();
         }
+        let held = self.window.front().map_or(1.0, |&(_, g)| g);
+        
+        // Box average of the held minimum over the same window
+        let slot = self.counter % self.lookahead;
+        self.counter += 1;
+        self.smooth_sum += (held - self.gains[slot]) as f64;
+        self.gains[slot] = held;
+        let smoothed = (self.smooth_sum / self.lookahead as f64) as f32;
+        
+        self.gain = if smoothed < self.gain {
+            smoothed
+        } else {
+            self.gain + (smoothed - self.gain) * self.release_coeff
+        };
         
-        let mut sig = input;
-        sig = self.allpass1.process(sig, 0.7);
-        sig = self.allpass2.process(sig, 0.5);
-        sig = self.allpass3.process(sig, 0.3);
-        sig = self.allpass4.process(sig, 0.1);
+        // Audio is delayed by lookahead - 1 (plus the true-peak latency) so the ramp completes on the peak
+        let delay_len = self.delay_len;
+        let read = (self.pos + 1) % delay_len;
+        self.delay[0][self.pos % delay_len] = left;
+        self.delay[1][self.pos % delay_len] = right;
+        let (out_left, out_right) = (self.delay[0][read], self.delay[1][read]);
+        self.pos = (self.pos + 1) % delay_len;
         
-        input * (1.0 - self.mix) + sig * self.mix
+        let reduction = -20.0 * self.gain.max(1.0e-6).log10();
+        self.max_reduction = self.max_reduction.max(reduction);
+        
+        // Safety net for the smoothing's float error
+        let ceiling = self.ceiling;
+        let (out_left, out_right) = (out_left * self.gain, out_right * self.gain);
+        (out_left.max(-ceiling).min(ceiling), out_right.max(-ceiling).min(ceiling))
+    }
+}
+
+/// BUILD 024: Filter parameter model version
+/// v0/v1: `filter_q * (1 + filter_damping * 0.5)` drove the SVF (clamped at 0.99) and
+///        `resonance` was ignored
+/// v2: `resonance` (0..1) drives every filter model and `filter_damping` reduces resonance
+///     towards the top of the cutoff range; `filter_q` is deprecated and only read by the migration
+const FILTER_PARAM_VERSION: u32 = 2;
+
+/// Returns (resonance, damping) in the current model for settings saved under `version`
+fn migrate_filter_params(version: u32, resonance: f32, filter_q: f32, damping: f32) -> (f32, f32) {
+    match version {
+        0 | 1 => {
+            // Old damping widened Q; fold it into resonance and start from no v2 damping
+            let effective_q = filter_q.max(0.0).min(1.0) * (1.0 + damping.max(0.0).min(1.0) * 0.5);
+            (effective_q.min(1.0), 0.0)
+        }
+        _ => (resonance.max(0.0).min(1.0), damping.max(0.0).min(1.0)),
     }
 }
 
 #[wasm_bindgen]
 pub struct IndustrialEngine {
     sample_rate: f32,
-    seed: u32,
     drive: f32,
     voices: Vec<Voice>,
     
+    noise: NoiseGenerator,
     noise_level: f32,
     noise_gate_follow: bool,
     noise_drone_enabled: bool,
+    noise_osc_level: f32,
+    noise_osc_type: NoiseType,
+    noise_density: f32,
+    noise_digital_rate: f32,
+    noise_digital_short: bool,
+    
+    // BUILD 024: Noise layer filter & envelope
+    noise_filter: SVFilter,
+    noise_cutoff: f32,
+    noise_resonance: f32,
+    noise_env_level: f32,
+    noise_attack_ms: f32,
+    noise_release_ms: f32,
+    noise_attack_coeff: f32,
+    noise_release_coeff: f32,
     
     fm_level: f32,
     
     global_fold: f32,
+    fold_type: FoldType,
+    fold_bias: f32,
     global_bitcrush: f32,
+    decimate_rate_hz: f32,
+    decimate_jitter: f32,
+    crush_curve: CrushCurve,
+    crush_dither: CrushDither,
     global_cutoff: f32,
     global_resonance: f32,
     global_feedback: f32,
@@ -1151,10 +4198,15 @@ pub struct IndustrialEngine {
     sub_detune: f32,
     sat_drive: f32,
     sat_mix: f32,
+    sat_model: SatModel,
+    sat_bias: f32,
+    sat_tone: f32,
     tilt: f32,
     post_gain: f32,
     limiter_threshold: f32,
     limiter_amount: f32,
+    limiter_mode: LimiterMode,
+    limiter: Limiter,
     
     lfo_rate_hz: f32,
     lfo_depth: f32,
@@ -1171,6 +4223,11 @@ pub struct IndustrialEngine {
     chorus_rate_hz: f32,
     chorus_depth_ms: f32,
     chorus_feedback: f32,
+    chorus_mode: ChorusMode,
+    chorus_taps: usize,
+    chorus_through_zero: bool,
+    chorus_global: bool,
+    master_chorus: Chorus,
     
     spasm: f32,
     
@@ -1182,6 +4239,13 @@ pub struct IndustrialEngine {
     jitter_to_pitch: f32,
     
     synth_type: usize,
+    osc2_type: usize,
+    osc_coarse: [f32; 2],
+    osc_fine: [f32; 2],
+    osc_level: [f32; 2],
+    osc_fm_amount: f32,
+    osc_ring_amount: f32,
+    osc_sync: bool,
     fm_ratio: f32,
     wavetable_position: f32,
     wave_morph_speed: f32,
@@ -1196,14 +4260,29 @@ pub struct IndustrialEngine {
     modal_stiffness: f32,
     modal_inharmonicity: f32,
     
-    filter_q: f32,
     filter_damping: f32,
     filter_drive: f32,
+    filter_type: FilterType,
+    filter_mode: FilterMode,
+    filter_morph: f32,
+    filter_slope_24: bool,
     
     chaos_lfo: ChaosLfo,
     chaos_enabled: bool,
     spectral_drift: SpectralDrift,
     diffusion: Diffusion,
+    delay: StereoDelay,
+    reverb: Reverb,
+    convolver: Convolver,
+    eq: ParametricEq,
+    multiband: Multiband,
+    stereo_width: StereoWidth,
+    compressor: Compressor,
+    sidechain_source: SidechainSource,
+    sidechain_note: i32,
+    sidechain_key: f32,
+    sidechain_buffer: Vec<f32>,
+    sidechain_pos: usize,
     
     sync_amount: f32,
     ring_ratio: f32,
@@ -1212,6 +4291,27 @@ pub struct IndustrialEngine {
     comb_freq: f32,
     comb_feedback: f32,
     comb_damp: f32,
+    comb_mode: CombMode,
+    comb_negative: bool,
+    comb_key_track: bool,
+    comb_key_semitones: f32,
+    
+    formant_mix: f32,
+    formant_vowel: f32,
+    formant_voice_set: usize,
+    lfo_to_vowel: f32,
+    sh_to_vowel: f32,
+    
+    filter_key_track: f32,
+    filter_key_center_hz: f32,
+    filter_env_attack: f32,
+    filter_env_decay: f32,
+    filter_env_sustain: f32,
+    filter_env_release: f32,
+    filter_env_amount: f32,
+    filter_env_velocity: f32,
+    
+    fx_chain: FxChain,
 }
 
 #[wasm_bindgen]
@@ -1224,27 +4324,52 @@ impl IndustrialEngine {
         
         Self {
             sample_rate,
-            seed: 12345,
             drive: 1.0,
             voices,
+            noise: NoiseGenerator::new(sample_rate, 12345),
             noise_level: 0.0,
             noise_gate_follow: true,
             noise_drone_enabled: false,
+            noise_osc_level: 0.0,
+            noise_osc_type: NoiseType::White,
+            noise_density: 1000.0,
+            noise_digital_rate: 8000.0,
+            noise_digital_short: false,
+            
+            noise_filter: SVFilter::new(sample_rate),
+            noise_cutoff: 20000.0,
+            noise_resonance: 0.0,
+            noise_env_level: 0.0,
+            noise_attack_ms: 5.0,
+            noise_release_ms: 300.0,
+            noise_attack_coeff: 1.0 - (-1.0 / (5.0 * 0.001 * sample_rate)).exp(),
+            noise_release_coeff: 1.0 - (-1.0 / (300.0 * 0.001 * sample_rate)).exp(),
             fm_level: 1.0,
             global_fold: 2.0,
+            fold_type: FoldType::Modulo,
+            fold_bias: 0.0,
             global_bitcrush: 8.0,
+            decimate_rate_hz: sample_rate,
+            decimate_jitter: 0.0,
+            crush_curve: CrushCurve::Linear,
+            crush_dither: CrushDither::None,
             global_cutoff: 2000.0,
-            global_resonance: 2.0,
+            global_resonance: 0.5,
             global_feedback: 0.3,
             
             sub_level: 0.0,
             sub_detune: 0.0,
             sat_drive: 1.0,
             sat_mix: 0.0,
+            sat_model: SatModel::Asymmetric,
+            sat_bias: 0.0,
+            sat_tone: 0.0,
             tilt: 0.0,
             post_gain: 1.0,
             limiter_threshold: 0.9,
             limiter_amount: 0.5,
+            limiter_mode: LimiterMode::Lookahead,
+            limiter: Limiter::new(sample_rate),
             
             lfo_rate_hz: 1.0,
             lfo_depth: 0.0,
@@ -1261,6 +4386,11 @@ impl IndustrialEngine {
             chorus_rate_hz: 0.5,
             chorus_depth_ms: 10.0,
             chorus_feedback: 0.3,
+            chorus_mode: ChorusMode::Chorus,
+            chorus_taps: 1,
+            chorus_through_zero: false,
+            chorus_global: false,
+            master_chorus: Chorus::new(sample_rate),
             
             spasm: 0.0,
             
@@ -1272,6 +4402,13 @@ impl IndustrialEngine {
             jitter_to_pitch: 0.0,
             
             synth_type: 0,
+            osc2_type: 0,
+            osc_coarse: [0.0, 0.0],
+            osc_fine: [0.0, 0.0],
+            osc_level: [1.0, 0.0],
+            osc_fm_amount: 0.0,
+            osc_ring_amount: 0.0,
+            osc_sync: false,
             fm_ratio: 2.0,
             wavetable_position: 0.0,
             wave_morph_speed: 0.0,
@@ -1286,14 +4423,29 @@ impl IndustrialEngine {
             modal_stiffness: 0.5,
             modal_inharmonicity: 0.0,
             
-            filter_q: 0.5,
             filter_damping: 0.0,
             filter_drive: 1.0,
+            filter_type: FilterType::Svf,
+            filter_mode: FilterMode::Lowpass,
+            filter_morph: 0.0,
+            filter_slope_24: false,
             
             chaos_lfo: ChaosLfo::new(sample_rate),
             chaos_enabled: false,
             spectral_drift: SpectralDrift::new(sample_rate),
-            diffusion: Diffusion::new(),
+            diffusion: Diffusion::new(sample_rate),
+            delay: StereoDelay::new(sample_rate),
+            reverb: Reverb::new(sample_rate),
+            convolver: Convolver::new(sample_rate),
+            eq: ParametricEq::new(sample_rate),
+            multiband: Multiband::new(sample_rate),
+            stereo_width: StereoWidth::new(sample_rate),
+            compressor: Compressor::new(sample_rate),
+            sidechain_source: SidechainSource::Internal,
+            sidechain_note: -1,
+            sidechain_key: 0.0,
+            sidechain_buffer: Vec::with_capacity(128),
+            sidechain_pos: 0,
             
             sync_amount: 0.0,
             ring_ratio: 1.0,
@@ -1302,6 +4454,27 @@ impl IndustrialEngine {
             comb_freq: 200.0,
             comb_feedback: 0.5,
             comb_damp: 0.5,
+            comb_mode: CombMode::Feedback,
+            comb_negative: false,
+            comb_key_track: false,
+            comb_key_semitones: 0.0,
+            
+            formant_mix: 0.0,
+            formant_vowel: 0.0,
+            formant_voice_set: 0,
+            lfo_to_vowel: 0.0,
+            sh_to_vowel: 0.0,
+            
+            filter_key_track: 0.0,
+            filter_key_center_hz: 261.63,
+            filter_env_attack: 5.0,
+            filter_env_decay: 300.0,
+            filter_env_sustain: 0.0,
+            filter_env_release: 300.0,
+            filter_env_amount: 0.0,
+            filter_env_velocity: 0.0,
+            
+            fx_chain: FxChain::default_order(),
         }
     }
     
@@ -1321,6 +4494,57 @@ impl IndustrialEngine {
         self.noise_drone_enabled = enabled;
     }
     
+    pub fn set_noise_filter(&mut self, cutoff: f32, resonance: f32) {
+        self.noise_cutoff = cutoff.max(20.0).min(20000.0);
+        self.noise_resonance = resonance.max(0.0).min(1.0);
+    }
+    
+    pub fn set_noise_envelope(&mut self, attack_ms: f32, release_ms: f32) {
+        self.noise_attack_ms = attack_ms.max(0.1).min(5000.0);
+        self.noise_release_ms = release_ms.max(0.1).min(10000.0);
+        self.noise_attack_coeff = 1.0 - (-1.0 / (self.noise_attack_ms * 0.001 * self.sample_rate)).exp();
+        self.noise_release_coeff = 1.0 - (-1.0 / (self.noise_release_ms * 0.001 * self.sample_rate)).exp();
+    }
+    
+    pub fn set_noise_type(&mut self, noise_type: usize) {
+        self.noise.set_type(NoiseType::from_index(noise_type));
+    }
+    
+    pub fn set_noise_density(&mut self, hz: f32) {
+        self.noise_density = hz.max(1.0).min(10000.0);
+        self.noise.set_density(self.noise_density);
+        for voice in &mut self.voices {
+            voice.noise_osc.set_density(self.noise_density);
+        }
+    }
+    
+    pub fn set_noise_digital(&mut self, rate_hz: f32, short_mode: bool) {
+        self.noise_digital_rate = rate_hz.max(20.0).min(self.sample_rate * 0.5);
+        self.noise_digital_short = short_mode;
+        self.noise.set_digital(self.noise_digital_rate, short_mode);
+        for voice in &mut self.voices {
+            voice.noise_osc.set_digital(self.noise_digital_rate, short_mode);
+        }
+    }
+    
+    pub fn set_noise_osc_level(&mut self, level: f32) {
+        self.noise_osc_level = level.max(0.0).min(1.0);
+        for voice in &mut self.voices {
+            if voice.is_active() {
+                voice.set_noise_osc_level(self.noise_osc_level);
+            }
+        }
+    }
+    
+    pub fn set_noise_osc_type(&mut self, noise_type: usize) {
+        self.noise_osc_type = NoiseType::from_index(noise_type);
+        for voice in &mut self.voices {
+            if voice.is_active() {
+                voice.set_noise_osc_type(self.noise_osc_type);
+            }
+        }
+    }
+    
     pub fn set_fm_level(&mut self, level: f32) {
         self.fm_level = level.max(0.0).min(1.0);
     }
@@ -1332,6 +4556,20 @@ impl IndustrialEngine {
         }
     }
     
+    pub fn set_fold_type(&mut self, fold_type: usize) {
+        self.fold_type = FoldType::from_index(fold_type);
+        for voice in &mut self.voices {
+            voice.set_fold_type(self.fold_type);
+        }
+    }
+    
+    pub fn set_fold_bias(&mut self, bias: f32) {
+        self.fold_bias = bias.max(-1.0).min(1.0);
+        for voice in &mut self.voices {
+            voice.set_fold_bias(self.fold_bias);
+        }
+    }
+    
     pub fn set_bit_depth(&mut self, depth: f32) {
         self.global_bitcrush = depth.max(1.0).min(16.0);
         for voice in &mut self.voices {
@@ -1339,6 +4577,28 @@ impl IndustrialEngine {
         }
     }
     
+    pub fn set_decimator(&mut self, rate_hz: f32, jitter: f32) {
+        self.decimate_rate_hz = rate_hz.max(100.0).min(self.sample_rate);
+        self.decimate_jitter = jitter.max(0.0).min(1.0);
+        for voice in &mut self.voices {
+            voice.set_decimator(self.decimate_rate_hz, self.decimate_jitter);
+        }
+    }
+    
+    pub fn set_crush_curve(&mut self, curve: usize) {
+        self.crush_curve = CrushCurve::from_index(curve);
+        for voice in &mut self.voices {
+            voice.set_crush_curve(self.crush_curve);
+        }
+    }
+    
+    pub fn set_crush_dither(&mut self, dither: usize) {
+        self.crush_dither = CrushDither::from_index(dither);
+        for voice in &mut self.voices {
+            voice.set_crush_dither(self.crush_dither);
+        }
+    }
+    
     pub fn set_cutoff(&mut self, freq: f32) {
         self.global_cutoff = freq.max(20.0).min(20000.0);
         for voice in &mut self.voices {
@@ -1376,8 +4636,24 @@ impl IndustrialEngine {
         self.sat_mix = mix.max(0.0).min(1.0);
     }
     
+    pub fn set_saturation_model(&mut self, model: usize) {
+        self.sat_model = SatModel::from_index(model);
+        for voice in &mut self.voices {
+            voice.set_saturation_model(self.sat_model);
+        }
+    }
+    
+    pub fn set_saturation_character(&mut self, bias: f32, tone: f32) {
+        self.sat_bias = bias.max(-1.0).min(1.0);
+        self.sat_tone = tone.max(-1.0).min(1.0);
+        for voice in &mut self.voices {
+            voice.set_saturation_character(self.sat_bias, self.sat_tone);
+        }
+    }
+    
     pub fn set_tilt(&mut self, value: f32) {
         self.tilt = value.max(-1.0).min(1.0);
+        self.eq.set_tilt(self.tilt);
     }
     
     pub fn set_post_gain(&mut self, gain: f32) {
@@ -1389,6 +4665,21 @@ impl IndustrialEngine {
         self.limiter_threshold = 0.9 - (amount * 0.4);
     }
     
+    /// 0 = clip (BUILD 023 soft-knee clamp), 1 = lookahead brickwall
+    pub fn set_limiter_mode(&mut self, mode: usize) {
+        self.limiter_mode = LimiterMode::from_index(mode);
+        self.limiter.reset();
+    }
+    
+    pub fn set_limiter_params(&mut self, lookahead_ms: f32, release_ms: f32, ceiling_db: f32, true_peak: bool) {
+        self.limiter.set_params(lookahead_ms, release_ms, ceiling_db, true_peak);
+    }
+    
+    /// Peak gain reduction in dB since the previous call (0 in clip mode)
+    pub fn limiter_gain_reduction(&mut self) -> f32 {
+        self.limiter.take_gain_reduction_db()
+    }
+    
     pub fn set_lfo(&mut self, rate_hz: f32, depth: f32, shape: i32) {
         self.lfo_rate_hz = rate_hz.max(0.05).min(40.0);
         self.lfo_depth = depth.max(0.0).min(1.0);
@@ -1422,6 +4713,21 @@ impl IndustrialEngine {
         self.chorus_feedback = feedback.max(0.0).min(0.99);
     }
     
+    pub fn set_chorus_mode(&mut self, mode: usize, taps: usize, through_zero: bool) {
+        self.chorus_mode = ChorusMode::from_index(mode);
+        self.chorus_taps = taps.max(1).min(4);
+        self.chorus_through_zero = through_zero;
+        self.master_chorus.set_mode(self.chorus_mode, self.chorus_taps, self.chorus_through_zero);
+        for voice in &mut self.voices {
+            voice.set_chorus_mode(self.chorus_mode, self.chorus_taps, self.chorus_through_zero);
+        }
+    }
+    
+    /// Run the chorus once on the voice sum instead of inside every voice
+    pub fn set_chorus_global(&mut self, enabled: bool) {
+        self.chorus_global = enabled;
+    }
+    
     pub fn set_spasm(&mut self, value: f32) {
         self.spasm = value.max(0.0).min(1.0);
         
@@ -1445,40 +4751,41 @@ impl IndustrialEngine {
     }
     
     pub fn note_on(&mut self, note_id: i32, frequency: f32, mod_index: f32) {
-        for voice in &mut self.voices {
-            if !voice.is_active() {
-                voice.note_on(note_id, frequency, mod_index);
-                voice.set_fold_amount(self.global_fold);
-                voice.set_bit_depth(self.global_bitcrush);
-                voice.set_cutoff(self.global_cutoff);
-                voice.set_resonance(self.global_resonance);
-                voice.set_feedback(self.global_feedback);
-                voice.set_sync_amount(self.sync_amount);
-                voice.set_ring_ratio(self.ring_ratio);
-                voice.set_ring_mix(self.ring_mix);
-                voice.set_comb_mix(self.comb_mix);
-                voice.set_comb_freq(self.comb_freq);
-                voice.set_comb_feedback(self.comb_feedback);
-                voice.set_comb_damp(self.comb_damp);
-                voice.set_wavetable_position(self.wavetable_position);
-                return;
-            }
-        }
+        self.note_on_velocity(note_id, frequency, mod_index, 1.0);
+    }
+    
+    pub fn note_on_velocity(&mut self, note_id: i32, frequency: f32, mod_index: f32, velocity: f32) {
+        let index = self.voices.iter().position(|voice| !voice.is_active()).unwrap_or(0);
         
-        self.voices[0].note_on(note_id, frequency, mod_index);
-        self.voices[0].set_fold_amount(self.global_fold);
-        self.voices[0].set_bit_depth(self.global_bitcrush);
-        self.voices[0].set_cutoff(self.global_cutoff);
-        self.voices[0].set_resonance(self.global_resonance);
-        self.voices[0].set_feedback(self.global_feedback);
-        self.voices[0].set_sync_amount(self.sync_amount);
-        self.voices[0].set_ring_ratio(self.ring_ratio);
-        self.voices[0].set_ring_mix(self.ring_mix);
-        self.voices[0].set_comb_mix(self.comb_mix);
-        self.voices[0].set_comb_freq(self.comb_freq);
-        self.voices[0].set_comb_feedback(self.comb_feedback);
-        self.voices[0].set_comb_damp(self.comb_damp);
-        self.voices[0].set_wavetable_position(self.wavetable_position);
+        let mut voices = std::mem::take(&mut self.voices);
+        voices[index].note_on(note_id, frequency, mod_index, velocity);
+        self.apply_voice_params(&mut voices[index]);
+        self.voices = voices;
+    }
+    
+    fn apply_voice_params(&self, voice: &mut Voice) {
+        voice.set_fold_amount(self.global_fold);
+        voice.set_bit_depth(self.global_bitcrush);
+        voice.set_cutoff(self.global_cutoff);
+        voice.set_resonance(self.global_resonance);
+        voice.set_feedback(self.global_feedback);
+        voice.set_sync_amount(self.sync_amount);
+        voice.set_ring_ratio(self.ring_ratio);
+        voice.set_ring_mix(self.ring_mix);
+        voice.set_comb_mix(self.comb_mix);
+        voice.set_comb_freq(self.comb_freq);
+        voice.set_comb_feedback(self.comb_feedback);
+        voice.set_comb_damp(self.comb_damp);
+        voice.set_comb_mode(self.comb_mode, self.comb_negative);
+        voice.set_comb_key_track(self.comb_key_track, self.comb_key_semitones);
+        voice.set_wavetable_position(self.wavetable_position);
+        voice.set_noise_osc_level(self.noise_osc_level);
+        voice.set_noise_osc_type(self.noise_osc_type);
+        voice.set_formant(self.formant_mix, self.formant_vowel, self.formant_voice_set);
+        voice.set_formant_mod(self.lfo_to_vowel, self.sh_to_vowel);
+        voice.set_filter_key_track(self.filter_key_track, self.filter_key_center_hz);
+        voice.set_filter_env(self.filter_env_attack, self.filter_env_decay, self.filter_env_sustain, self.filter_env_release);
+        voice.set_filter_env_amount(self.filter_env_amount, self.filter_env_velocity);
     }
     
     pub fn note_off(&mut self, note_id: i32) {
@@ -1512,94 +4819,177 @@ impl IndustrialEngine {
         }
     }
     
-    fn noise_sample(&mut self) -> f32 {
-        let mut x = self.seed;
-        x ^= x << 13;
-        x ^= x >> 17;
-        x ^= x << 5;
-        self.seed = x;
+    pub fn process(&mut self, output: &mut [f32]) {
+        let (chaos_value, drift_value) = self.block_modulation();
+        
+        for sample in output.iter_mut() {
+            let mix = self.mix_sample(chaos_value, drift_value);
+            let (left, right) = self.master_sample(mix);
+            *sample = (left + right) * 0.5;
+        }
+        self.end_block();
+    }
+    
+    /// BUILD 024: Stereo render; the master bus (diffusion onwards) runs in stereo
+    pub fn process_stereo(&mut self, left: &mut [f32], right: &mut [f32]) {
+        let (chaos_value, drift_value) = self.block_modulation();
         
-        (x as f32 / std::u32::MAX as f32) * 2.0 - 1.0
+        for (l, r) in left.iter_mut().zip(right.iter_mut()) {
+            let mix = self.mix_sample(chaos_value, drift_value);
+            let (out_l, out_r) = self.master_sample(mix);
+            *l = out_l;
+            *r = out_r;
+        }
+        self.end_block();
     }
     
-    pub fn process(&mut self, output: &mut [f32]) {
+    /// The JS sidechain buffer is consumed once, by the next render call
+    fn end_block(&mut self) {
+        self.sidechain_buffer.clear();
+        self.sidechain_pos = 0;
+    }
+    
+    fn block_modulation(&mut self) -> (f32, f32) {
         let chaos_value = if self.chaos_enabled {
             self.chaos_lfo.process()
         } else {
             0.0
         };
         
-        let drift_value = self.spectral_drift.process();
+        (chaos_value, self.spectral_drift.process())
+    }
+    
+    /// Voices, noise layer and drive summed to mono
+    fn mix_sample(&mut self, chaos_value: f32, drift_value: f32) -> f32 {
+        let voice_chorus_mix = if self.chorus_global { 0.0 } else { self.chorus_mix };
         
-        for sample in output.iter_mut() {
-            let mut mix = 0.0;
-            
-            for voice in &mut self.voices {
-                if voice.is_active() {
-                    let voice_sample = voice.process_sample(
-                        self.sub_level,
-                        self.sub_detune,
-                        self.sat_drive,
-                        self.sat_mix,
-                        self.tilt,
-                        self.lfo_depth,
-                        self.lfo_shape,
-                        self.sh_depth,
-                        self.jitter_amount,
-                        self.jitter_band_hz,
-                        self.chorus_mix,
-                        self.chorus_rate_hz,
-                        self.chorus_depth_ms,
-                        self.chorus_feedback,
-                        self.lfo_to_cutoff,
-                        self.lfo_to_fold,
-                        self.sh_to_cutoff,
-                        self.sh_to_fold,
-                        self.sh_to_bit,
-                        self.jitter_to_pitch,
-                        chaos_value,
-                        drift_value,
-                        self.synth_type,
-                        self.fm_ratio,
-                        self.harmonics_count,
-                        self.harmonic_rolloff,
-                        self.phase_dist_amount,
-                        self.vector_x,
-                        self.vector_y,
-                        self.grain_size,
-                        self.grain_density,
-                        self.modal_stiffness,
-                        self.modal_inharmonicity,
-                        self.filter_q,
-                        self.filter_damping,
-                        self.filter_drive,
-                    );
-                    mix += voice_sample * self.fm_level;
+        let mut mix = 0.0;
+        let mut env_sum = 0.0;
+        let mut any_held = false;
+        let mut key = 0.0;
+        
+        for voice in &mut self.voices {
+            if voice.is_active() {
+                let voice_sample = voice.process_sample(
+                    self.sub_level,
+                    self.sub_detune,
+                    self.sat_drive,
+                    self.sat_mix,
+                    self.lfo_depth,
+                    self.lfo_shape,
+                    self.sh_depth,
+                    self.jitter_amount,
+                    self.jitter_band_hz,
+                    voice_chorus_mix,
+                    self.chorus_rate_hz,
+                    self.chorus_depth_ms,
+                    self.chorus_feedback,
+                    self.lfo_to_cutoff,
+                    self.lfo_to_fold,
+                    self.sh_to_cutoff,
+                    self.sh_to_fold,
+                    self.sh_to_bit,
+                    self.jitter_to_pitch,
+                    chaos_value,
+                    drift_value,
+                    self.fm_ratio,
+                    self.harmonics_count,
+                    self.harmonic_rolloff,
+                    self.phase_dist_amount,
+                    self.vector_x,
+                    self.vector_y,
+                    self.grain_size,
+                    self.grain_density,
+                    self.modal_stiffness,
+                    self.modal_inharmonicity,
+                    self.filter_damping,
+                    self.filter_drive,
+                );
+                mix += voice_sample * self.fm_level;
+                if voice.get_note_id() == self.sidechain_note {
+                    key += voice_sample * self.fm_level;
                 }
+                env_sum += voice.envelope_level();
+                any_held |= voice.is_held();
             }
-            
-            if self.noise_level > 0.0 {
-                let noise = self.noise_sample() * self.noise_level;
-                mix += noise;
-            }
-            
-            mix *= self.drive;
+        }
+        
+        // === NOISE LAYER ===
+        // Drone: continuous bed / Gate follow: summed voice envelopes / else: own AR envelope gated by held notes
+        let noise_target = if self.noise_drone_enabled {
+            1.0
+        } else if self.noise_gate_follow {
+            env_sum.min(1.0)
+        } else if any_held {
+            1.0
+        } else {
+            0.0
+        };
+        let noise_coeff = if noise_target > self.noise_env_level {
+            self.noise_attack_coeff
+        } else {
+            self.noise_release_coeff
+        };
+        self.noise_env_level = flush_denormal(self.noise_env_level + (noise_target - self.noise_env_level) * noise_coeff);
+        
+        if self.noise_level > 0.0 && self.noise_env_level > 1.0e-5 {
+            let cutoff = self.noise_cutoff.min(self.sample_rate * 0.45);
+            let noise = self.noise_filter.process(self.noise.tick(), cutoff, self.noise_resonance);
+            mix += noise * self.noise_level * self.noise_env_level;
+        }
+        
+        // === GLOBAL CHORUS ===
+        if self.chorus_global && self.chorus_mix > 0.0 {
+            mix = self.master_chorus.process(mix, self.chorus_mix, self.chorus_rate_hz, self.chorus_depth_ms, self.chorus_feedback);
+        }
+        
+        self.sidechain_key = key;
+        
+        mix *= self.drive;
+        if self.limiter_mode == LimiterMode::Clip {
             mix = mix.max(-1.0).min(1.0);
-            
-            mix = self.diffusion.process(mix);
-            
-            mix *= self.post_gain;
-            
-            // BUILD 023: Enhanced limiter with soft knee
-            if mix.abs() > self.limiter_threshold {
-                let excess = mix.abs() - self.limiter_threshold;
-                let reduction = excess * self.limiter_amount;
-                let soft_limited = reduction.tanh() * (1.0 - self.limiter_threshold);
-                mix = mix.signum() * (self.limiter_threshold + soft_limited);
+        }
+        
+        mix
+    }
+    
+    fn master_sample(&mut self, input: f32) -> (f32, f32) {
+        let (left, right) = self.diffusion.process(input);
+        let (left, right) = self.delay.process(left, right);
+        let (left, right) = self.reverb.process(left, right);
+        let (left, right) = self.convolver.process(left, right);
+        let (left, right) = self.eq.process(left, right);
+        let (left, right) = self.multiband.process(left, right);
+        let (left, right) = self.stereo_width.process(left, right);
+        
+        let key = match self.sidechain_source {
+            SidechainSource::Internal => left.abs().max(right.abs()),
+            SidechainSource::External => {
+                let sample = self.sidechain_buffer.get(self.sidechain_pos).copied().unwrap_or(0.0);
+                self.sidechain_pos += 1;
+                sample
             }
-            
-            *sample = mix;
+            SidechainSource::Note => self.sidechain_key,
+        };
+        let (left, right) = self.compressor.process(left, right, key);
+        
+        let (left, right) = (left * self.post_gain, right * self.post_gain);
+        
+        match self.limiter_mode {
+            LimiterMode::Clip => (self.limit(left), self.limit(right)),
+            LimiterMode::Lookahead => self.limiter.process(left, right),
+        }
+    }
+    
+    // BUILD 023: Enhanced limiter with soft knee
+    fn limit(&self, mut mix: f32) -> f32 {
+        if mix.abs() > self.limiter_threshold {
+            let excess = mix.abs() - self.limiter_threshold;
+            let reduction = excess * self.limiter_amount;
+            let soft_limited = reduction.tanh() * (1.0 - self.limiter_threshold);
+            mix = mix.signum() * (self.limiter_threshold + soft_limited);
         }
+        mix
     }
     
     pub fn set_chaos_mode(&mut self, mode: usize) {
@@ -1636,8 +5026,175 @@ impl IndustrialEngine {
         self.diffusion.set_mix(mix);
     }
     
+    pub fn set_diffusion(&mut self, size: f32, modulation: f32) {
+        self.diffusion.set_size(size);
+        self.diffusion.set_modulation(modulation);
+    }
+    
+    pub fn set_delay(&mut self, mix: f32, time_ms: f32, feedback: f32, mode: usize) {
+        self.delay.set_params(mix, time_ms, feedback, DelayMode::from_index(mode));
+    }
+    
+    /// Division: 0=1/1 1=1/2 2=1/4 3=1/8 4=1/16 5=1/4. 6=1/8. 7=1/4T 8=1/8T
+    pub fn set_delay_sync(&mut self, enabled: bool, bpm: f32, division: usize) {
+        self.delay.set_sync(enabled, bpm, division);
+    }
+    
+    pub fn set_delay_feedback_tone(&mut self, low_cut_hz: f32, high_cut_hz: f32) {
+        self.delay.set_feedback_tone(low_cut_hz, high_cut_hz);
+    }
+    
+    pub fn set_delay_grime(&mut self, drive: f32, bit_depth: f32, crush_rate_hz: f32) {
+        self.delay.set_grime(drive, bit_depth, crush_rate_hz);
+    }
+    
+    pub fn set_delay_duck(&mut self, amount: f32) {
+        self.delay.set_duck(amount);
+    }
+    
+    pub fn set_delay_modulation(&mut self, depth: f32, rate_hz: f32) {
+        self.delay.set_modulation(depth, rate_hz);
+    }
+    
+    /// Band type: 0 = peak, 1 = low shelf, 2 = high shelf, 3 = low cut, 4 = high cut.
+    /// `slope_db` (12/24/36/48) applies to the cuts, `q` to the others.
+    pub fn set_eq_band(&mut self, index: usize, band_type: usize, freq: f32, gain_db: f32, q: f32, slope_db: u32) {
+        self.eq.set_band(index, EqBandType::from_index(band_type), freq, gain_db, q, slope_db);
+    }
+    
+    pub fn set_eq_band_enabled(&mut self, index: usize, enabled: bool) {
+        self.eq.set_band_enabled(index, enabled);
+    }
+    
+    pub fn set_multiband(&mut self, mix: f32, low_hz: f32, high_hz: f32) {
+        self.multiband.set_mix(mix);
+        self.multiband.set_crossovers(low_hz, high_hz);
+    }
+    
+    /// Band 0 = low, 1 = mid, 2 = high; `model` as in `set_saturation_model`, drive 0 bypasses
+    /// the saturator and bit depth 16 bypasses the crusher
+    pub fn set_multiband_band(&mut self, index: usize, model: usize, drive: f32, bit_depth: f32, crush_rate_hz: f32, gain_db: f32) {
+        self.multiband.set_band(index, SatModel::from_index(model), drive, bit_depth, crush_rate_hz, gain_db);
+    }
+    
+    pub fn set_stereo_width(&mut self, width: f32) {
+        self.stereo_width.set_width(width);
+    }
+    
+    pub fn set_bass_mono(&mut self, enabled: bool, freq_hz: f32) {
+        self.stereo_width.set_bass_mono(enabled, freq_hz);
+    }
+    
+    pub fn set_haas(&mut self, delay_ms: f32, amount: f32) {
+        self.stereo_width.set_haas(delay_ms, amount);
+    }
+    
+    pub fn set_compressor(&mut self, threshold_db: f32, ratio: f32, knee_db: f32, makeup_db: f32, mix: f32) {
+        self.compressor.set_params(threshold_db, ratio, knee_db, makeup_db, mix);
+    }
+    
+    /// Detector: 0 = peak, 1 = RMS
+    pub fn set_compressor_timing(&mut self, attack_ms: f32, release_ms: f32, detector: usize) {
+        self.compressor.set_timing(attack_ms, release_ms, DetectorMode::from_index(detector));
+    }
+    
+    /// Source: 0 = master bus, 1 = external input (worklet input 0 or `sidechain-buffer`), 2 = voice(s) playing `note_id`
+    pub fn set_compressor_sidechain(&mut self, source: usize, note_id: i32) {
+        self.sidechain_source = SidechainSource::from_index(source);
+        self.sidechain_note = note_id;
+    }
+    
+    /// Sidechain audio for the next `process` / `process_stereo` call
+    pub fn set_sidechain_input(&mut self, input: &[f32]) {
+        self.sidechain_buffer.clear();
+        self.sidechain_buffer.extend_from_slice(input);
+        self.sidechain_pos = 0;
+    }
+    
+    /// Peak compressor gain reduction in dB since the previous call
+    pub fn compressor_gain_reduction(&mut self) -> f32 {
+        self.compressor.take_gain_reduction_db()
+    }
+    
+    pub fn set_reverb(&mut self, mix: f32, size: f32, decay: f32, predelay_ms: f32) {
+        self.reverb.set_params(mix, size, decay, predelay_ms);
+    }
+    
+    pub fn set_reverb_tone(&mut self, damping: f32, modulation: f32) {
+        self.reverb.set_tone(damping, modulation);
+    }
+    
+    pub fn set_reverb_freeze(&mut self, freeze: bool) {
+        self.reverb.set_freeze(freeze);
+    }
+    
+    pub fn reset_reverb(&mut self) {
+        self.reverb.reset();
+    }
+    
+    /// Build convolution spectra for `load_impulse_response` from an IR at `sample_rate`
+    /// (max 4 s; pass an empty `right` for mono). Call this on the main thread: it runs
+    /// every partition FFT, which is too heavy for the audio thread.
+    pub fn prepare_impulse_response(left: &[f32], right: &[f32], normalize: bool, sample_rate: f32) -> Vec<f32> {
+        Convolver::prepare(left, right, normalize, sample_rate)
+    }
+    
+    /// Load spectra from `prepare_impulse_response`; only copies, no FFTs or allocation.
+    /// Returns false (and clears the IR) if the spectra are malformed or too long.
+    pub fn load_impulse_response(&mut self, spectra: &[f32]) -> bool {
+        self.convolver.load(spectra)
+    }
+    
+    pub fn clear_impulse_response(&mut self) {
+        self.convolver.clear();
+    }
+    
+    pub fn set_convolution(&mut self, mix: f32, predelay_ms: f32) {
+        self.convolver.set_params(mix, predelay_ms);
+    }
+    
     pub fn set_synth_type(&mut self, synth_type: usize) {
         self.synth_type = synth_type.min(6);
+        for voice in &mut self.voices {
+            voice.set_osc_type(0, self.synth_type);
+        }
+    }
+    
+    pub fn set_osc2_type(&mut self, synth_type: usize) {
+        self.osc2_type = synth_type.min(6);
+        for voice in &mut self.voices {
+            voice.set_osc_type(1, self.osc2_type);
+        }
+    }
+    
+    pub fn set_osc_tuning(&mut self, slot: usize, coarse: f32, fine: f32) {
+        if slot > 1 {
+            return;
+        }
+        self.osc_coarse[slot] = coarse.max(-48.0).min(48.0).round();
+        self.osc_fine[slot] = fine.max(-100.0).min(100.0);
+        for voice in &mut self.voices {
+            voice.set_osc_tuning(slot, self.osc_coarse[slot], self.osc_fine[slot]);
+        }
+    }
+    
+    pub fn set_osc_level(&mut self, slot: usize, level: f32) {
+        if slot > 1 {
+            return;
+        }
+        self.osc_level[slot] = level.max(0.0).min(1.0);
+        for voice in &mut self.voices {
+            voice.set_osc_level(slot, self.osc_level[slot]);
+        }
+    }
+    
+    pub fn set_osc_cross_mod(&mut self, fm_amount: f32, ring_amount: f32, sync: bool) {
+        self.osc_fm_amount = fm_amount.max(0.0).min(1.0);
+        self.osc_ring_amount = ring_amount.max(0.0).min(1.0);
+        self.osc_sync = sync;
+        for voice in &mut self.voices {
+            voice.set_osc_cross_mod(self.osc_fm_amount, self.osc_ring_amount, self.osc_sync);
+        }
     }
     
     pub fn set_fm_ratio(&mut self, ratio: f32) {
@@ -1697,18 +5254,68 @@ impl IndustrialEngine {
         self.modal_inharmonicity = inharmonicity.max(0.0).min(1.0);
     }
     
+    /// Deprecated: the v1 "Q" control. `q` is read as a v1 Q with no v1 damping and
+    /// migrated to resonance; the current damping is left alone. Use `set_resonance`.
     pub fn set_filter_q(&mut self, q: f32) {
-        self.filter_q = q.max(0.0).min(1.0);
+        let (resonance, _) = migrate_filter_params(1, self.global_resonance, q, 0.0);
+        self.set_resonance(resonance);
     }
     
     pub fn set_filter_damping(&mut self, damping: f32) {
         self.filter_damping = damping.max(0.0).min(1.0);
     }
     
+    pub fn filter_param_version(&self) -> u32 {
+        FILTER_PARAM_VERSION
+    }
+    
+    /// Load filter settings saved under `version`, migrating older models to the current one
+    pub fn load_filter_params(&mut self, version: u32, resonance: f32, filter_q: f32, damping: f32) {
+        let (resonance, damping) = migrate_filter_params(version, resonance, filter_q, damping);
+        self.set_resonance(resonance);
+        self.set_filter_damping(damping);
+    }
+    
+    pub fn resonance(&self) -> f32 {
+        self.global_resonance
+    }
+    
+    pub fn filter_damping(&self) -> f32 {
+        self.filter_damping
+    }
+    
     pub fn set_filter_drive(&mut self, drive: f32) {
         self.filter_drive = drive.max(0.1).min(10.0);
     }
     
+    pub fn set_filter_type(&mut self, filter_type: usize) {
+        self.filter_type = FilterType::from_index(filter_type);
+        for voice in &mut self.voices {
+            voice.set_filter_type(self.filter_type);
+        }
+    }
+    
+    pub fn set_filter_mode(&mut self, mode: usize) {
+        self.filter_mode = FilterMode::from_index(mode);
+        for voice in &mut self.voices {
+            voice.set_filter_mode(self.filter_mode);
+        }
+    }
+    
+    pub fn set_filter_morph(&mut self, morph: f32) {
+        self.filter_morph = morph.max(0.0).min(1.0);
+        for voice in &mut self.voices {
+            voice.set_filter_morph(self.filter_morph);
+        }
+    }
+    
+    pub fn set_filter_slope(&mut self, slope_db: u32) {
+        self.filter_slope_24 = slope_db >= 24;
+        for voice in &mut self.voices {
+            voice.set_filter_slope_24(self.filter_slope_24);
+        }
+    }
+    
     pub fn set_sync_amount(&mut self, amount: f32) {
         self.sync_amount = amount.max(0.0).min(5.0);
         for voice in &mut self.voices {
@@ -1771,4 +5378,405 @@ impl IndustrialEngine {
             }
         }
     }
+    
+    pub fn set_comb_mode(&mut self, mode: usize, negative: bool) {
+        self.comb_mode = CombMode::from_index(mode);
+        self.comb_negative = negative;
+        for voice in &mut self.voices {
+            if voice.is_active() {
+                voice.set_comb_mode(self.comb_mode, self.comb_negative);
+            }
+        }
+    }
+    
+    pub fn set_comb_key_track(&mut self, enabled: bool, semitones: f32) {
+        self.comb_key_track = enabled;
+        self.comb_key_semitones = semitones.max(-24.0).min(24.0);
+        for voice in &mut self.voices {
+            if voice.is_active() {
+                voice.set_comb_key_track(self.comb_key_track, self.comb_key_semitones);
+            }
+        }
+    }
+    
+    pub fn set_formant(&mut self, mix: f32, vowel: f32, voice_set: usize) {
+        self.formant_mix = mix.max(0.0).min(1.0);
+        self.formant_vowel = vowel.max(0.0).min(4.0);
+        self.formant_voice_set = voice_set.min(2);
+        for voice in &mut self.voices {
+            if voice.is_active() {
+                voice.set_formant(self.formant_mix, self.formant_vowel, self.formant_voice_set);
+            }
+        }
+    }
+    
+    pub fn set_formant_mod(&mut self, lfo_to_vowel: f32, sh_to_vowel: f32) {
+        self.lfo_to_vowel = lfo_to_vowel.max(0.0).min(1.0);
+        self.sh_to_vowel = sh_to_vowel.max(0.0).min(1.0);
+        for voice in &mut self.voices {
+            if voice.is_active() {
+                voice.set_formant_mod(self.lfo_to_vowel, self.sh_to_vowel);
+            }
+        }
+    }
+    
+    pub fn set_filter_key_track(&mut self, amount: f32, center_note: f32) {
+        self.filter_key_track = amount.max(0.0).min(1.0);
+        self.filter_key_center_hz = 440.0 * 2.0_f32.powf((center_note.max(0.0).min(127.0) - 69.0) / 12.0);
+        for voice in &mut self.voices {
+            voice.set_filter_key_track(self.filter_key_track, self.filter_key_center_hz);
+        }
+    }
+    
+    pub fn set_filter_env(&mut self, attack_ms: f32, decay_ms: f32, sustain: f32, release_ms: f32) {
+        self.filter_env_attack = attack_ms;
+        self.filter_env_decay = decay_ms;
+        self.filter_env_sustain = sustain;
+        self.filter_env_release = release_ms;
+        for voice in &mut self.voices {
+            voice.set_filter_env(attack_ms, decay_ms, sustain, release_ms);
+        }
+    }
+    
+    pub fn set_filter_env_amount(&mut self, amount: f32, velocity_sens: f32) {
+        self.filter_env_amount = amount.max(-1.0).min(1.0);
+        self.filter_env_velocity = velocity_sens.max(0.0).min(1.0);
+        for voice in &mut self.voices {
+            voice.set_filter_env_amount(self.filter_env_amount, self.filter_env_velocity);
+        }
+    }
+    
+    /// Set the per-voice stage order, e.g. `"ring>sub>fold|comb>filter>crush>chorus"`.
+    /// An invalid route is rejected and the current order is kept. A legacy `tilt` stage is
+    /// dropped (the tilt control now drives the master EQ) and reported as a warning.
+    pub fn set_fx_chain(&mut self, route: &str) -> Result<Option<String>, String> {
+        self.fx_chain = FxChain::parse(route)?;
+        for voice in &mut self.voices {
+            voice.set_fx_chain(self.fx_chain.clone());
+        }
+        let warning = self.fx_chain.legacy_tilt.then(|| {
+            format!("'tilt' in route '{}' now maps to the master EQ tilt (set_tilt)", route)
+        });
+        Ok(warning)
+    }
+    
+    pub fn reset_fx_chain(&mut self) {
+        self.fx_chain = FxChain::default_order();
+        for voice in &mut self.voices {
+            voice.set_fx_chain(self.fx_chain.clone());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn modulation_leaves_base_values_untouched() {
+        let mut engine = IndustrialEngine::new(48000.0);
+        engine.set_bit_depth(12.0);
+        engine.set_cutoff(3000.0);
+        engine.set_fold_amount(3.0);
+        engine.set_formant(0.5, 2.0, 0);
+        engine.set_formant_mod(1.0, 1.0);
+        engine.set_lfo(5.0, 1.0, 0);
+        engine.set_sample_hold(50.0, 1.0, 0.0);
+        engine.set_jitter(1.0, 50.0);
+        engine.set_mod_routing(1.0, 1.0, 1.0, 1.0, 1.0, 1.0);
+        engine.note_on(60, 261.63, 2.0);
+        
+        let mut buffer = vec![0.0; 128];
+        for _ in 0..200 {
+            engine.process(&mut buffer);
+        }
+        
+        let voice = &engine.voices[0];
+        assert!(voice.is_active());
+        assert_eq!(voice.bit_depth.base, 12.0);
+        assert_eq!(voice.cutoff.base, 3000.0);
+        assert_eq!(voice.fold_amount.base, 3.0);
+        assert_eq!(voice.formant_vowel.base, 2.0);
+        assert_eq!(voice.frequency.base, 261.63);
+        // Modulation is still applied to the rendered values
+        assert_ne!(voice.bit_depth.value, voice.bit_depth.base);
+    }
+    
+    #[test]
+    fn vibrato_respects_mix() {
+        let mut dry_chorus = Chorus::new(48000.0);
+        let mut wet_chorus = Chorus::new(48000.0);
+        dry_chorus.set_mode(ChorusMode::Vibrato, 1, false);
+        wet_chorus.set_mode(ChorusMode::Vibrato, 1, false);
+        
+        let input = test_signal(4800);
+        let mut wet_differs = false;
+        for &x in &input {
+            assert_eq!(dry_chorus.process(x, 0.0, 5.0, 8.0, 0.5), x);
+            wet_differs |= (wet_chorus.process(x, 1.0, 5.0, 8.0, 0.5) - x).abs() > 1e-3;
+        }
+        assert!(wet_differs);
+    }
+    
+    #[test]
+    fn filter_envelope_retriggers_from_current_level() {
+        let mut env = FilterEnvelope::new(48000.0);
+        env.set_adsr(10.0, 10.0, 0.5, 100.0);
+        env.trigger();
+        for _ in 0..2400 {
+            env.tick();
+        }
+        assert!((env.tick() - 0.5).abs() < 1e-6);
+        
+        env.trigger();
+        let mut last = env.tick();
+        assert!(last >= 0.5, "retrigger dropped the envelope to {}", last);
+        for _ in 0..480 {
+            let level = env.tick();
+            assert!(level >= last);
+            last = level;
+        }
+        assert!((last - 1.0).abs() < 1e-6);
+    }
+    
+    #[test]
+    fn filter_key_track_and_envelope_reach_new_voices() {
+        let mut engine = IndustrialEngine::new(48000.0);
+        engine.set_filter_key_track(0.75, 69.0);
+        engine.set_filter_env(20.0, 400.0, 0.6, 800.0);
+        engine.set_filter_env_amount(-0.5, 0.4);
+        
+        let mut voice = Voice::new(48000.0);
+        engine.apply_voice_params(&mut voice);
+        assert_eq!(voice.key_track, 0.75);
+        assert!((voice.key_track_center_hz - 440.0).abs() < 1e-3);
+        assert_eq!(voice.filter_env.sustain, 0.6);
+        assert_eq!(voice.filter_env.release_ms, 800.0);
+        assert_eq!(voice.filter_env_amount, -0.5);
+        assert_eq!(voice.filter_env_velocity, 0.4);
+    }
+    
+    #[test]
+    fn filter_params_migrate_from_v1() {
+        let mut engine = IndustrialEngine::new(48000.0);
+        // v1 damping widened Q: 0.6 * (1 + 0.5 * 0.5) = 0.75
+        engine.load_filter_params(1, 0.0, 0.6, 0.5);
+        assert!((engine.resonance() - 0.75).abs() < 1e-6);
+        assert_eq!(engine.filter_damping(), 0.0);
+        
+        engine.load_filter_params(FILTER_PARAM_VERSION, 0.4, 0.9, 0.3);
+        assert_eq!(engine.resonance(), 0.4);
+        assert_eq!(engine.filter_damping(), 0.3);
+    }
+    
+    #[test]
+    fn fx_chain_default_route_round_trips() {
+        let parsed = FxChain::parse("ring>sub>sat>filter>formant>fold>crush>comb>chorus").unwrap();
+        let default = FxChain::default_order();
+        assert_eq!(parsed.steps, default.steps);
+        // Noise joins right after ring and sub, as before routing existed
+        assert_eq!(default.noise_step, 2);
+        assert_eq!(parsed.noise_step, 2);
+    }
+    
+    #[test]
+    fn fx_chain_parses_parallel_groups() {
+        let chain = FxChain::parse(" Ring > fold|comb > filter ").unwrap();
+        assert_eq!(chain.steps, vec![
+            vec![FxStage::Ring],
+            vec![FxStage::Wavefold, FxStage::Comb],
+            vec![FxStage::Filter],
+        ]);
+        assert_eq!(chain.noise_step, 1);
+        assert_eq!(FxChain::parse("filter>crush").unwrap().noise_step, 0);
+    }
+    
+    #[test]
+    fn fx_chain_rejects_invalid_routes() {
+        for (route, expected) in [
+            ("ring>>filter", "empty stage"),
+            ("ring|", "empty stage"),
+            ("", "empty stage"),
+            ("ring>phaser", "unknown stage 'phaser'"),
+            ("ring>fold>filter|ring", "appears more than once"),
+            ("sat>saturation", "appears more than once"),
+        ] {
+            let err = FxChain::parse(route).unwrap_err();
+            assert!(err.contains(expected), "'{}' gave '{}'", route, err);
+        }
+    }
+    
+    #[test]
+    fn fx_chain_maps_legacy_tilt_to_master_eq() {
+        let chain = FxChain::parse("ring>tilt>crush|tilt").unwrap();
+        assert_eq!(chain.steps, vec![vec![FxStage::Ring], vec![FxStage::Bitcrush]]);
+        assert!(chain.legacy_tilt);
+        
+        let mut engine = IndustrialEngine::new(48000.0);
+        let warning = engine.set_fx_chain("ring>tilt>crush").unwrap();
+        assert!(warning.is_some_and(|w| w.contains("master EQ tilt")));
+        assert_eq!(engine.set_fx_chain("ring>crush").unwrap(), None);
+    }
+    
+    #[test]
+    fn eq_tilt_pivots_at_1khz() {
+        let sample_rate = 48000.0;
+        let mut eq = ParametricEq::new(sample_rate);
+        eq.set_tilt(1.0);
+        let output: Vec<f32> = (0..sample_rate as usize / 4)
+            .map(|n| eq.process((std::f32::consts::TAU * 1000.0 * n as f32 / sample_rate).sin(), 0.0).0)
+            .collect();
+        let peak = settled_peak(&output);
+        assert!((peak - 1.0).abs() < 0.01, "1 kHz came out at {}", peak);
+    }
+    
+    #[test]
+    fn haas_at_zero_ms_leaves_channels_aligned() {
+        let mut width = StereoWidth::new(48000.0);
+        width.set_haas(0.0, 1.0);
+        for x in test_signal(256) {
+            assert_eq!(width.process(x, x), (x, x));
+        }
+    }
+    
+    /// Deterministic broadband test signal
+    fn test_signal(len: usize) -> Vec<f32> {
+        (0..len).map(|n| ((n * 7919) % 257) as f32 / 128.0 - 1.0).collect()
+    }
+    
+    #[test]
+    fn convolver_unit_impulse_delays_by_one_block() {
+        let mut convolver = Convolver::new(48000.0);
+        assert!(convolver.load(&Convolver::prepare(&[1.0], &[], false, 48000.0)));
+        convolver.set_params(1.0, 0.0);
+        
+        let input = test_signal(CONV_BLOCK * 6 + 17);
+        for (n, &x) in input.iter().enumerate() {
+            let (left, right) = convolver.process(x, -x);
+            let expected = if n >= CONV_BLOCK { input[n - CONV_BLOCK] } else { 0.0 };
+            assert!((left - expected).abs() < 1e-4, "sample {}: {} vs {}", n, left, expected);
+            assert!((right + expected).abs() < 1e-4, "sample {}: {} vs {}", n, right, -expected);
+        }
+    }
+    
+    #[test]
+    fn convolver_rejects_spectra_over_capacity() {
+        let mut convolver = Convolver::new(48000.0);
+        let long_ir = vec![0.5; (CONV_MAX_IR_SECONDS * 96000.0) as usize];
+        assert!(!convolver.load(&Convolver::prepare(&long_ir, &[], false, 96000.0)));
+        assert_eq!(convolver.process(0.25, -0.25), (0.25, -0.25));
+    }
+    
+    #[test]
+    fn convolver_keeps_running_at_zero_mix() {
+        let mut convolver = Convolver::new(48000.0);
+        assert!(convolver.load(&Convolver::prepare(&[1.0], &[], false, 48000.0)));
+        
+        let input = test_signal(CONV_BLOCK * 8);
+        for (n, &x) in input.iter().enumerate() {
+            // Silent at mix 0 for the first half, then the wet path must be current
+            if n == input.len() / 2 {
+                convolver.set_params(1.0, 0.0);
+            }
+            let (left, _) = convolver.process(x, x);
+            if n >= input.len() / 2 {
+                assert!((left - input[n - CONV_BLOCK]).abs() < 1e-4, "sample {}", n);
+            } else {
+                assert_eq!(left, x);
+            }
+        }
+    }
+    
+    #[test]
+    fn master_effects_do_not_replay_stale_tails() {
+        let sample_rate = 48000.0;
+        let mut reverb = Reverb::new(sample_rate);
+        let mut delay = StereoDelay::new(sample_rate);
+        reverb.set_params(1.0, 1.0, 0.7, 0.0);
+        delay.set_params(1.0, 300.0, 0.6, DelayMode::Stereo);
+        
+        // Excite both, mute them, let the silence run, then bring the mix back
+        for x in test_signal(4800) {
+            reverb.process(x, x);
+            delay.process(x, x);
+        }
+        reverb.set_params(0.0, 1.0, 0.7, 0.0);
+        delay.set_params(0.0, 300.0, 0.6, DelayMode::Stereo);
+        for _ in 0..(sample_rate as usize * 6) {
+            reverb.process(0.0, 0.0);
+            delay.process(0.0, 0.0);
+        }
+        reverb.set_params(1.0, 1.0, 0.7, 0.0);
+        delay.set_params(1.0, 300.0, 0.6, DelayMode::Stereo);
+        
+        let mut peak: f32 = 0.0;
+        for _ in 0..(sample_rate as usize) {
+            let (l, r) = reverb.process(0.0, 0.0);
+            let (dl, dr) = delay.process(0.0, 0.0);
+            peak = peak.max(l.abs()).max(r.abs()).max(dl.abs()).max(dr.abs());
+        }
+        assert!(peak < 1e-3, "stale tail at {}", peak);
+    }
+    
+    /// Reference true peak: 64-tap Hann-windowed sinc reconstruction at 16 points per sample
+    fn reference_true_peak(samples: &[f32]) -> f32 {
+        let half = 32;
+        let mut peak: f32 = 0.0;
+        for n in half..samples.len() - half {
+            for step in 0..16 {
+                let t = step as f32 / 16.0;
+                let mut value = 0.0;
+                for k in 1 - half as i32..=half as i32 {
+                    let x = t - k as f32;
+                    let sinc = if x == 0.0 { 1.0 } else { (PI * x).sin() / (PI * x) };
+                    let window = 0.5 * (1.0 + (PI * x / half as f32).cos());
+                    value += samples[(n as i32 + k) as usize] * sinc * window;
+                }
+                peak = peak.max(value.abs());
+            }
+        }
+        peak
+    }
+    
+    #[test]
+    fn true_peak_limiter_holds_inter_sample_peaks_under_the_ceiling() {
+        let mut limiter = Limiter::new(48000.0);
+        let ceiling_db = -1.0;
+        let ceiling = 10.0_f32.powf(ceiling_db / 20.0);
+        // A short lookahead, so a late detector isn't hidden by a long gain ramp
+        limiter.set_params(0.5, 100.0, ceiling_db, true);
+        
+        // fs/4 sine at 45°: every sample is ±0.707 (under the ceiling), the true peak is 1.0.
+        // It starts abruptly, so the first peaks test the gain ramp's timing.
+        let output: Vec<f32> = (0..1600)
+            .map(|n| {
+                let x = if n < 1000 { 0.0 } else { (PI * 0.5 * n as f32 + PI * 0.25).sin() };
+                limiter.process(x, x).0
+            })
+            .collect();
+        let peak = reference_true_peak(&output);
+        assert!(peak <= ceiling * 1.005, "true peak {} over ceiling {}", peak, ceiling);
+    }
+    
+    /// Peak level of the second half of `samples` (past the filter settling time)
+    fn settled_peak(samples: &[f32]) -> f32 {
+        samples[samples.len() / 2..].iter().fold(0.0_f32, |peak, s| peak.max(s.abs()))
+    }
+    
+    #[test]
+    fn multiband_half_mix_is_flat_at_the_crossovers() {
+        let sample_rate = 48000.0;
+        for freq in [150.0, 2500.0] {
+            let mut multiband = Multiband::new(sample_rate);
+            multiband.set_mix(0.5);
+            let output: Vec<f32> = (0..sample_rate as usize)
+                .map(|n| {
+                    let x = (std::f32::consts::TAU * freq * n as f32 / sample_rate).sin();
+                    multiband.process(x, x).0
+                })
+                .collect();
+            let peak = settled_peak(&output);
+            assert!((peak - 1.0).abs() < 0.01, "{} Hz came out at {}", freq, peak);
+        }
+    }
 }